@@ -5,7 +5,10 @@ use super::DisplayHandle;
 
 /// Raw display handle for Android.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[repr(C)]
 pub struct AndroidDisplayHandle {}
 
 impl AndroidDisplayHandle {
@@ -18,7 +21,7 @@ impl AndroidDisplayHandle {
     /// # use raw_window_handle::AndroidDisplayHandle;
     /// let handle = AndroidDisplayHandle::new();
     /// ```
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Self {}
     }
 }
@@ -45,10 +48,18 @@ impl DisplayHandle<'static> {
 
 /// Raw window handle for Android NDK.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AndroidNdkWindowHandle {
     /// A pointer to an `ANativeWindow`.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::non_null"))]
     pub a_native_window: NonNull<c_void>,
+    /// A pointer to the `JavaVM`, if known.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::option_non_null"))]
+    pub jvm: Option<NonNull<c_void>>,
+    /// A JNI `jobject` reference to the `Activity` that owns the window, if known.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::option_non_null"))]
+    pub activity: Option<NonNull<c_void>>,
 }
 
 impl AndroidNdkWindowHandle {
@@ -64,9 +75,32 @@ impl AndroidNdkWindowHandle {
     /// #
     /// let ptr: NonNull<ANativeWindow>;
     /// # ptr = NonNull::from(&());
-    /// let handle = AndroidNdkWindowHandle::new(ptr.cast());
+    /// let handle = AndroidNdkWindowHandle::new(ptr.cast())
+    ///     // Optionally, set the `JavaVM` and activity too.
+    ///     .with_jvm(None)
+    ///     .with_activity(None);
     /// ```
     pub fn new(a_native_window: NonNull<c_void>) -> Self {
-        Self { a_native_window }
+        Self {
+            a_native_window,
+            jvm: None,
+            activity: None,
+        }
+    }
+
+    /// Set the `JavaVM` of the application.
+    ///
+    /// See [`new`][Self::new] for an example.
+    pub fn with_jvm(mut self, jvm: Option<NonNull<c_void>>) -> Self {
+        self.jvm = jvm;
+        self
+    }
+
+    /// Set the `Activity` that owns the window.
+    ///
+    /// See [`new`][Self::new] for an example.
+    pub fn with_activity(mut self, activity: Option<NonNull<c_void>>) -> Self {
+        self.activity = activity;
+        self
     }
 }
@@ -5,7 +5,10 @@ use super::DisplayHandle;
 
 /// Raw display handle for AppKit.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[repr(C)]
 pub struct AppKitDisplayHandle {}
 
 impl AppKitDisplayHandle {
@@ -18,7 +21,7 @@ impl AppKitDisplayHandle {
     /// # use raw_window_handle::AppKitDisplayHandle;
     /// let handle = AppKitDisplayHandle::new();
     /// ```
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Self {}
     }
 }
@@ -84,10 +87,25 @@ impl DisplayHandle<'static> {
 /// # }
 /// ```
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AppKitWindowHandle {
     /// A pointer to an `NSView` object.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::non_null"))]
     pub ns_view: NonNull<c_void>,
+    /// A pointer to the `NSWindow` that the view is installed in, if known.
+    ///
+    /// This is useful when the view may not be installed in the window yet, in which case
+    /// retrieving the window from the view would fail. If provided, the pointer must be valid for
+    /// the lifetime of the handle.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::option_non_null"))]
+    pub ns_window: Option<NonNull<c_void>>,
+    /// Whether the windowing library has already made the view layer-backed, by calling
+    /// `setWantsLayer:` with `YES`.
+    ///
+    /// Attaching a `CAMetalLayer` to a view that is not layer-backed may crash, so renderers
+    /// should make the view layer-backed themselves if this is `false`.
+    pub wants_layer: bool,
 }
 
 impl AppKitWindowHandle {
@@ -107,9 +125,108 @@ impl AppKitWindowHandle {
     /// let ns_window: Retained<NSWindow> = ...;
     /// let ns_view: Retained<NSView> = window.contentView();
     /// let ns_view: NonNull<NSView> = NonNull::from(&*ns_view);
-    /// let handle = AppKitWindowHandle::new(ns_view.cast());
+    /// let handle = AppKitWindowHandle::new(ns_view.cast())
+    ///     // Optionally, set the window too.
+    ///     .with_ns_window(Some(NonNull::from(&*ns_window).cast()));
     /// ```
     pub fn new(ns_view: NonNull<c_void>) -> Self {
-        Self { ns_view }
+        Self {
+            ns_view,
+            ns_window: None,
+            wants_layer: false,
+        }
+    }
+
+    /// Set the `NSWindow` of the view.
+    ///
+    /// See [`new`][Self::new] for an example.
+    pub fn with_ns_window(mut self, ns_window: Option<NonNull<c_void>>) -> Self {
+        self.ns_window = ns_window;
+        self
+    }
+
+    /// Set whether the view has already been made layer-backed.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::AppKitWindowHandle;
+    /// # type NSView = ();
+    /// #
+    /// let ns_view: NonNull<NSView>;
+    /// # ns_view = NonNull::from(&());
+    /// # #[cfg(only_for_showcase)]
+    /// unsafe { ns_view.setWantsLayer(true) };
+    /// let handle = AppKitWindowHandle::new(ns_view.cast()).with_wants_layer(true);
+    /// assert!(handle.wants_layer);
+    /// ```
+    pub fn with_wants_layer(mut self, wants_layer: bool) -> Self {
+        self.wants_layer = wants_layer;
+        self
+    }
+}
+
+#[cfg(all(target_os = "macos", feature = "objc2-0-5"))]
+#[cfg_attr(docsrs, doc(cfg(all(target_os = "macos", feature = "objc2-0-5"))))]
+/// These implementations are only available when `objc2-0-5` is enabled.
+impl AppKitWindowHandle {
+    /// Create a new `AppKitWindowHandle` from an [`objc2_app_kit::NSView`].
+    ///
+    /// The lifetime of the view should be at least as long as the lifetime of this.
+    pub fn from_ns_view(ns_view: &objc2_app_kit::NSView) -> Self {
+        Self::new(NonNull::from(ns_view).cast())
+    }
+
+    /// Retain the underlying [`objc2_app_kit::NSView`].
+    ///
+    /// # Safety
+    ///
+    /// The inner pointer must be valid. This is ensured if this handle was
+    /// borrowed from [`WindowHandle`][crate::WindowHandle].
+    pub unsafe fn retain_ns_view(&self) -> objc2::rc::Retained<objc2_app_kit::NSView> {
+        // SAFETY: Upheld by the caller.
+        let ns_view = unsafe { objc2::rc::Retained::retain(self.ns_view.as_ptr().cast()) };
+        // Unwrap is fine, since the pointer came from `NonNull`.
+        ns_view.unwrap()
+    }
+}
+
+/// Raw window handle for a `CAMetalLayer` on AppKit.
+///
+/// This may be provided by windowing libraries that have already created a `CAMetalLayer` for the
+/// window, to save graphics libraries from having to create one from the view.
+///
+/// Note that, like the view itself, the layer should only be accessed from the main thread of the
+/// application. This struct is `!Send` and `!Sync` to help with ensuring that.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AppKitMetalLayerWindowHandle {
+    /// A pointer to a `CAMetalLayer` object.
+    ///
+    /// This must point to a valid `CAMetalLayer` for the lifetime of the handle.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::non_null"))]
+    pub layer: NonNull<c_void>,
+}
+
+impl AppKitMetalLayerWindowHandle {
+    /// Create a new handle to a `CAMetalLayer`.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::AppKitMetalLayerWindowHandle;
+    /// # type CAMetalLayer = ();
+    /// #
+    /// let layer: NonNull<CAMetalLayer>;
+    /// # layer = NonNull::from(&());
+    /// let handle = AppKitMetalLayerWindowHandle::new(layer.cast());
+    /// ```
+    pub fn new(layer: NonNull<c_void>) -> Self {
+        Self { layer }
     }
 }
@@ -0,0 +1,207 @@
+//! Implementations of [`arbitrary::Arbitrary`] for fuzzing.
+//!
+//! Every pointer is [dangling][NonNull::dangling], and optional pointers are either dangling or
+//! `None`. Integer fields, such as IDs and file descriptors, are arbitrary.
+
+use core::num::{NonZeroIsize, NonZeroU32};
+use core::ptr::NonNull;
+
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{
+    AndroidDisplayHandle, AndroidNdkWindowHandle, AppKitDisplayHandle,
+    AppKitMetalLayerWindowHandle, AppKitWindowHandle, CustomWindowHandle, DmaBufWindowHandle,
+    DrmDisplayHandle, DrmWindowHandle, FuchsiaViewWindowHandle, GbmDisplayHandle, GbmWindowHandle,
+    HaikuDisplayHandle, HaikuWindowHandle, HeadlessDisplayHandle, NullWindowHandle,
+    OhosDisplayHandle, OhosNdkWindowHandle, OrbitalDisplayHandle, OrbitalWindowHandle,
+    PlayStationWindowHandle, RawDisplayHandle, RawDisplayHandleKind, RawWindowHandle,
+    RawWindowHandleKind, ScreenDisplayHandle, ScreenWindowHandle, SdlMetalWindowHandle,
+    SwitchDisplayHandle, SwitchWindowHandle, TizenWindowHandle, UiKitDisplayHandle, UiKitLayerKind,
+    UiKitMetalLayerWindowHandle, UiKitWindowHandle, VulkanDisplayHandle, WaylandDisplayHandle,
+    WaylandSubsurfaceWindowHandle, WaylandWindowHandle, WebCanvasSelectorWindowHandle,
+    WebCanvasWindowHandle, WebDisplayHandle, WebOffscreenCanvasWindowHandle, WebWindowHandle,
+    WebWorkerDisplayHandle, Win32WindowHandle, WinRtWindowHandle, WinUiSwapChainPanelWindowHandle,
+    WindowsDisplayHandle, XcbDisplayHandle, XcbWindowHandle, XlibDisplayHandle, XlibWindowHandle,
+};
+
+fn ptr<T>() -> NonNull<T> {
+    NonNull::dangling()
+}
+
+fn opt_ptr<T>(u: &mut Unstructured<'_>) -> Result<Option<NonNull<T>>> {
+    Ok(bool::arbitrary(u)?.then(ptr))
+}
+
+/// Generates handles of every kind, for fuzzing code that dispatches on the backend.
+///
+/// **The generated handles must never be used.** All pointers are [dangling][NonNull::dangling],
+/// and all IDs are arbitrary, so they do not refer to any real window.
+///
+/// This is only available when the `arbitrary` feature is enabled.
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+impl<'a> Arbitrary<'a> for RawWindowHandle {
+    // `c_ulong` is not `u64` on all platforms.
+    #[allow(clippy::useless_conversion)]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        use RawWindowHandleKind as K;
+
+        Ok(match *u.choose(RawWindowHandleKind::ALL)? {
+            K::UiKit => {
+                let mut h = UiKitWindowHandle::new(ptr());
+                h.ui_view_controller = opt_ptr(u)?;
+                h.layer_kind = *u.choose(&[
+                    UiKitLayerKind::Unknown,
+                    UiKitLayerKind::Eagl,
+                    UiKitLayerKind::Metal,
+                ])?;
+                h.into()
+            }
+            K::AppKit => {
+                let mut h = AppKitWindowHandle::new(ptr());
+                h.ns_window = opt_ptr(u)?;
+                h.wants_layer = bool::arbitrary(u)?;
+                h.into()
+            }
+            K::Orbital => OrbitalWindowHandle::new(ptr()).into(),
+            K::OhosNdk => OhosNdkWindowHandle::new(ptr()).into(),
+            K::Xlib => {
+                let mut h = XlibWindowHandle::new(u64::arbitrary(u)? as _);
+                h.visual_id = u64::arbitrary(u)? as _;
+                h.screen = Arbitrary::arbitrary(u)?;
+                h.depth = u8::arbitrary(u)?;
+                h.into()
+            }
+            K::Xcb => {
+                let mut h = XcbWindowHandle::new(NonZeroU32::arbitrary(u)?);
+                h.visual_id = Option::<NonZeroU32>::arbitrary(u)?;
+                h.screen = Arbitrary::arbitrary(u)?;
+                h.depth = u8::arbitrary(u)?;
+                h.into()
+            }
+            K::Wayland => {
+                let mut h = WaylandWindowHandle::new(ptr());
+                h.buffer_scale = Arbitrary::arbitrary(u)?;
+                h.viewport = opt_ptr(u)?;
+                h.xdg_surface = opt_ptr(u)?;
+                h.xdg_toplevel = opt_ptr(u)?;
+                h.into()
+            }
+            K::Drm => {
+                let mut h = DrmWindowHandle::new(u32::arbitrary(u)?);
+                h.connector_id = u32::arbitrary(u)?;
+                h.crtc_id = u32::arbitrary(u)?;
+                h.into()
+            }
+            K::Gbm => {
+                let mut h = GbmWindowHandle::new(ptr());
+                h.format = u32::arbitrary(u)?;
+                h.modifier = u64::arbitrary(u)?;
+                h.into()
+            }
+            K::Win32 => {
+                let mut h = Win32WindowHandle::new(NonZeroIsize::arbitrary(u)?);
+                h.hinstance = Option::<NonZeroIsize>::arbitrary(u)?;
+                h.hdc = Option::<NonZeroIsize>::arbitrary(u)?;
+                h.parent_hwnd = Option::<NonZeroIsize>::arbitrary(u)?;
+                h.into()
+            }
+            K::WinRt => WinRtWindowHandle::new(ptr()).into(),
+            K::Web => {
+                let mut h = WebWindowHandle::new(u32::arbitrary(u)?);
+                h.scale_factor = f64::arbitrary(u)?;
+                h.into()
+            }
+            K::WebCanvas => WebCanvasWindowHandle::new(ptr()).into(),
+            K::WebOffscreenCanvas => {
+                let mut h = WebOffscreenCanvasWindowHandle::new(ptr());
+                h.message_port = opt_ptr(u)?;
+                h.into()
+            }
+            K::AndroidNdk => {
+                let mut h = AndroidNdkWindowHandle::new(ptr());
+                h.jvm = opt_ptr(u)?;
+                h.activity = opt_ptr(u)?;
+                h.into()
+            }
+            K::Haiku => {
+                let mut h = HaikuWindowHandle::new(ptr());
+                h.b_direct_window = opt_ptr(u)?;
+                h.into()
+            }
+            K::Screen => ScreenWindowHandle::new(ptr(), ptr()).into(),
+            K::WaylandSubsurface => WaylandSubsurfaceWindowHandle::new(ptr(), ptr(), ptr()).into(),
+            K::Switch => SwitchWindowHandle::new(ptr()).into(),
+            K::AppKitMetalLayer => AppKitMetalLayerWindowHandle::new(ptr()).into(),
+            K::UiKitMetalLayer => UiKitMetalLayerWindowHandle::new(ptr()).into(),
+            // The selector is a dangling pointer, so it must be empty.
+            K::WebCanvasSelector => WebCanvasSelectorWindowHandle::new(ptr(), 0).into(),
+            K::Fuchsia => FuchsiaViewWindowHandle::new(ptr()).into(),
+            K::WinUi => WinUiSwapChainPanelWindowHandle::new(ptr()).into(),
+            K::DmaBuf => DmaBufWindowHandle::new(
+                Arbitrary::arbitrary(u)?,
+                u32::arbitrary(u)?,
+                u32::arbitrary(u)?,
+                u32::arbitrary(u)?,
+            )
+            .with_modifier(u64::arbitrary(u)?)
+            .into(),
+            K::PlayStation => {
+                let mut h = PlayStationWindowHandle::new(Arbitrary::arbitrary(u)?);
+                h.display_index = u32::arbitrary(u)?;
+                h.into()
+            }
+            K::Custom => CustomWindowHandle::new(u32::arbitrary(u)?, ptr()).into(),
+            K::Tizen => {
+                let mut h = TizenWindowHandle::new(ptr());
+                h.wl_surface = opt_ptr(u)?;
+                h.into()
+            }
+            K::Sdl => SdlMetalWindowHandle::new(ptr()).into(),
+            K::Null => NullWindowHandle::new().into(),
+        })
+    }
+}
+
+/// Generates handles of every kind, for fuzzing code that dispatches on the backend.
+///
+/// **The generated handles must never be used.** All pointers are [dangling][NonNull::dangling],
+/// and all IDs and file descriptors are arbitrary, so they do not refer to any real display.
+///
+/// This is only available when the `arbitrary` feature is enabled.
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+impl<'a> Arbitrary<'a> for RawDisplayHandle {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        use RawDisplayHandleKind as K;
+
+        Ok(match *u.choose(RawDisplayHandleKind::ALL)? {
+            K::UiKit => UiKitDisplayHandle::new().into(),
+            K::AppKit => AppKitDisplayHandle::new().into(),
+            K::Orbital => OrbitalDisplayHandle::new().into(),
+            K::Ohos => OhosDisplayHandle::new().into(),
+            K::Xlib => XlibDisplayHandle::new(opt_ptr(u)?, Arbitrary::arbitrary(u)?).into(),
+            K::Xcb => XcbDisplayHandle::new(opt_ptr(u)?, Arbitrary::arbitrary(u)?)
+                .with_fd(Arbitrary::arbitrary(u)?)
+                .into(),
+            K::Wayland => {
+                let mut h = WaylandDisplayHandle::new(ptr());
+                h.registry = opt_ptr(u)?;
+                h.event_queue = opt_ptr(u)?;
+                h.into()
+            }
+            K::Drm => DrmDisplayHandle::new(i32::arbitrary(u)?)
+                .with_lease(i32::arbitrary(u)?)
+                .with_render_node(bool::arbitrary(u)?)
+                .into(),
+            K::Gbm => GbmDisplayHandle::new(ptr()).into(),
+            K::Windows => WindowsDisplayHandle::new().into(),
+            K::Web => WebDisplayHandle::new().into(),
+            K::Android => AndroidDisplayHandle::new().into(),
+            K::Haiku => HaikuDisplayHandle::new().into(),
+            K::Screen => ScreenDisplayHandle::new(ptr()).into(),
+            K::Vulkan => VulkanDisplayHandle::new(ptr(), u64::arbitrary(u)?).into(),
+            K::Switch => SwitchDisplayHandle::new().into(),
+            K::WebWorker => WebWorkerDisplayHandle::new().into(),
+            K::Headless => HeadlessDisplayHandle::new().into(),
+        })
+    }
+}
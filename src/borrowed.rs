@@ -71,6 +71,45 @@ impl<H: HasDisplayHandle + ?Sized> HasDisplayHandle for alloc::sync::Arc<H> {
     }
 }
 
+impl<H: HasDisplayHandle + ?Sized> HasDisplayHandle for core::cell::Ref<'_, H> {
+    fn display_handle(&self) -> Result<DisplayHandle<'_>, HandleError> {
+        (**self).display_handle()
+    }
+}
+
+impl<P: core::ops::Deref> HasDisplayHandle for core::pin::Pin<P>
+where
+    P::Target: HasDisplayHandle,
+{
+    fn display_handle(&self) -> Result<DisplayHandle<'_>, HandleError> {
+        (**self).display_handle()
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<H: HasDisplayHandle + ?Sized> HasDisplayHandle for std::sync::MutexGuard<'_, H> {
+    fn display_handle(&self) -> Result<DisplayHandle<'_>, HandleError> {
+        (**self).display_handle()
+    }
+}
+
+/// Returns [`HandleError::Unavailable`] if there is no value.
+impl<H: HasDisplayHandle> HasDisplayHandle for Option<H> {
+    fn display_handle(&self) -> Result<DisplayHandle<'_>, HandleError> {
+        self.as_ref()
+            .ok_or(HandleError::Unavailable)?
+            .display_handle()
+    }
+}
+
+/// Delegates to the display `D`, for pairing separate window and display objects.
+impl<W, D: HasDisplayHandle> HasDisplayHandle for (W, D) {
+    fn display_handle(&self) -> Result<DisplayHandle<'_>, HandleError> {
+        self.1.display_handle()
+    }
+}
+
 /// The handle to the display controller of the windowing system.
 ///
 /// This is the primary return type of the [`HasDisplayHandle`] trait. It is guaranteed to contain
@@ -102,6 +141,9 @@ impl<'a> DisplayHandle<'a> {
     /// platform provides.
     ///
     /// It is not possible to invalidate a [`DisplayHandle`] on any platform without additional unsafe code.
+    ///
+    /// See [`WindowHandle::borrow_raw`] for an example of using this to bridge handles received
+    /// over FFI.
     pub unsafe fn borrow_raw(raw: RawDisplayHandle) -> Self {
         Self {
             raw,
@@ -110,9 +152,61 @@ impl<'a> DisplayHandle<'a> {
     }
 
     /// Get the underlying raw display handle.
+    #[inline]
     pub fn as_raw(&self) -> RawDisplayHandle {
         self.raw
     }
+
+    /// Get the display handle as a specific platform's handle type.
+    ///
+    /// Returns [`HandleError::NotSupported`] if the display handle is of a different kind than
+    /// `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use raw_window_handle::{DisplayHandle, HandleError, XlibDisplayHandle};
+    /// fn create_instance(display: DisplayHandle<'_>) -> Result<(), HandleError> {
+    ///     let xlib: XlibDisplayHandle = display.get()?;
+    ///     // Create an instance from the Xlib display here.
+    /// #   let _ = xlib;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn get<T: FromRawDisplayHandle>(&self) -> Result<T, HandleError> {
+        T::from_raw_display_handle(self.raw)
+    }
+
+    /// Extend the lifetime of the display handle to `'static`.
+    ///
+    /// See [`WindowHandle::into_static`] for when this may be useful.
+    ///
+    /// # Safety
+    ///
+    /// The display that this handle refers to must stay alive, and must remain valid in the sense
+    /// of [`DisplayHandle::borrow_raw`], for as long as the returned handle or any copy of it is
+    /// used.
+    pub unsafe fn into_static(self) -> DisplayHandle<'static> {
+        // SAFETY: Upheld by the caller.
+        unsafe { DisplayHandle::borrow_raw(self.raw) }
+    }
+}
+
+/// A platform-specific display handle type that can be extracted from a [`RawDisplayHandle`].
+///
+/// This is implemented for every display handle type in this crate, and is used by
+/// [`DisplayHandle::get`].
+pub trait FromRawDisplayHandle: Sized {
+    /// Extract the handle from `raw`.
+    ///
+    /// Returns [`HandleError::NotSupported`] if `raw` is of a different kind.
+    fn from_raw_display_handle(raw: RawDisplayHandle) -> Result<Self, HandleError>;
+}
+
+impl<T: TryFrom<RawDisplayHandle, Error = RawDisplayHandle>> FromRawDisplayHandle for T {
+    fn from_raw_display_handle(raw: RawDisplayHandle) -> Result<Self, HandleError> {
+        T::try_from(raw).map_err(|_| HandleError::NotSupported)
+    }
 }
 
 impl AsRef<RawDisplayHandle> for DisplayHandle<'_> {
@@ -133,12 +227,94 @@ impl From<DisplayHandle<'_>> for RawDisplayHandle {
     }
 }
 
+impl PartialEq<RawDisplayHandle> for DisplayHandle<'_> {
+    fn eq(&self, other: &RawDisplayHandle) -> bool {
+        self.raw == *other
+    }
+}
+
+impl PartialEq<DisplayHandle<'_>> for RawDisplayHandle {
+    fn eq(&self, other: &DisplayHandle<'_>) -> bool {
+        *self == other.raw
+    }
+}
+
 impl<'a> HasDisplayHandle for DisplayHandle<'a> {
     fn display_handle(&self) -> Result<DisplayHandle<'_>, HandleError> {
         Ok(*self)
     }
 }
 
+/// An owned, reference-counted display that can be shared between threads.
+///
+/// This is the owning counterpart to [`DisplayHandle`]: it keeps the object that provides the
+/// display alive for as long as any clone of the `OwnedDisplayHandle` exists, which makes it
+/// suitable for storing in long-lived render threads without tying them to a borrow.
+///
+/// # Example
+///
+/// ```
+/// # use raw_window_handle::{
+/// #     DisplayHandle, HandleError, HasDisplayHandle, OwnedDisplayHandle, RawDisplayHandle,
+/// #     WindowsDisplayHandle,
+/// # };
+/// struct Connection;
+///
+/// impl HasDisplayHandle for Connection {
+///     fn display_handle(&self) -> Result<DisplayHandle<'_>, HandleError> {
+///         Ok(DisplayHandle::windows())
+///     }
+/// }
+///
+/// let display = OwnedDisplayHandle::new(Connection);
+/// let thread_display = display.clone();
+/// std::thread::spawn(move || {
+///     let raw = thread_display.display_handle().unwrap().as_raw();
+///     assert_eq!(raw, RawDisplayHandle::Windows(WindowsDisplayHandle::new()));
+/// })
+/// .join()
+/// .unwrap();
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone)]
+pub struct OwnedDisplayHandle {
+    display: alloc::sync::Arc<dyn HasDisplayHandle + Send + Sync>,
+}
+
+#[cfg(feature = "alloc")]
+impl OwnedDisplayHandle {
+    /// Create a new `OwnedDisplayHandle` that takes ownership of `display`.
+    pub fn new(display: impl HasDisplayHandle + Send + Sync + 'static) -> Self {
+        Self {
+            display: alloc::sync::Arc::new(display),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<alloc::sync::Arc<dyn HasDisplayHandle + Send + Sync>> for OwnedDisplayHandle {
+    fn from(display: alloc::sync::Arc<dyn HasDisplayHandle + Send + Sync>) -> Self {
+        Self { display }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Debug for OwnedDisplayHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("OwnedDisplayHandle")
+            .field(&self.display.display_handle())
+            .finish()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl HasDisplayHandle for OwnedDisplayHandle {
+    fn display_handle(&self) -> Result<DisplayHandle<'_>, HandleError> {
+        self.display.display_handle()
+    }
+}
+
 /// A handle to a window.
 ///
 /// Objects that implement this trait should be able to return a [`WindowHandle`] for the window
@@ -161,6 +337,39 @@ impl<'a> HasDisplayHandle for DisplayHandle<'a> {
 pub trait HasWindowHandle {
     /// Get a handle to the window.
     fn window_handle(&self) -> Result<WindowHandle<'_>, HandleError>;
+
+    /// Get a copy of the raw window handle, detached from the lifetime of `self`.
+    ///
+    /// This is a snapshot: the handle was valid at the time of the call, but nothing guarantees
+    /// that it stays valid afterwards, since the window may be destroyed as soon as `self` is
+    /// dropped. It is intended for logging, or for use as a cache key, and must not be used to
+    /// access the window unless its lifetime is ensured by other means.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use raw_window_handle::{HasWindowHandle, RawWindowHandle, WebWindowHandle, WindowHandle};
+    /// # let raw = RawWindowHandle::from(WebWindowHandle::new(1));
+    /// # let window = unsafe { WindowHandle::borrow_raw(raw) };
+    /// let snapshot = window.raw_window_handle_snapshot().unwrap();
+    /// println!("rendering to {}", snapshot);
+    /// ```
+    fn raw_window_handle_snapshot(&self) -> Result<RawWindowHandle, HandleError> {
+        self.window_handle().map(Into::into)
+    }
+
+    /// Check whether the window handle is currently available.
+    ///
+    /// On some platforms, such as Android and iOS, the window is only available while the
+    /// application is in the foreground, and [`window_handle`][Self::window_handle] returns
+    /// [`HandleError::Unavailable`] otherwise. This can be used to cheaply check for that before
+    /// attempting operations on the window's surface.
+    ///
+    /// Note that the window may still become unavailable right after this returns `true`, so the
+    /// result of `window_handle` must still be handled.
+    fn is_window_available(&self) -> bool {
+        self.window_handle().is_ok()
+    }
 }
 
 impl<H: HasWindowHandle + ?Sized> HasWindowHandle for &H {
@@ -199,6 +408,113 @@ impl<H: HasWindowHandle + ?Sized> HasWindowHandle for alloc::sync::Arc<H> {
     }
 }
 
+impl<H: HasWindowHandle + ?Sized> HasWindowHandle for core::cell::Ref<'_, H> {
+    fn window_handle(&self) -> Result<WindowHandle<'_>, HandleError> {
+        (**self).window_handle()
+    }
+}
+
+impl<P: core::ops::Deref> HasWindowHandle for core::pin::Pin<P>
+where
+    P::Target: HasWindowHandle,
+{
+    fn window_handle(&self) -> Result<WindowHandle<'_>, HandleError> {
+        (**self).window_handle()
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<H: HasWindowHandle + ?Sized> HasWindowHandle for std::sync::MutexGuard<'_, H> {
+    fn window_handle(&self) -> Result<WindowHandle<'_>, HandleError> {
+        (**self).window_handle()
+    }
+}
+
+/// Returns [`HandleError::Unavailable`] if there is no value.
+impl<H: HasWindowHandle> HasWindowHandle for Option<H> {
+    fn window_handle(&self) -> Result<WindowHandle<'_>, HandleError> {
+        self.as_ref()
+            .ok_or(HandleError::Unavailable)?
+            .window_handle()
+    }
+}
+
+/// Delegates to the window `W`, for pairing separate window and display objects.
+///
+/// # Example
+///
+/// ```
+/// # use raw_window_handle::{
+/// #     DisplayHandle, HasWindowAndDisplayHandle, RawWindowHandle, WebWindowHandle, WindowHandle,
+/// # };
+/// fn create_surface(target: impl HasWindowAndDisplayHandle) {
+///     # let _ = target;
+///     // ...
+/// }
+///
+/// # let raw = RawWindowHandle::from(WebWindowHandle::new(1));
+/// let window = unsafe { WindowHandle::borrow_raw(raw) };
+/// let display = DisplayHandle::web();
+/// create_surface((window, display));
+/// ```
+impl<W: HasWindowHandle, D> HasWindowHandle for (W, D) {
+    fn window_handle(&self) -> Result<WindowHandle<'_>, HandleError> {
+        self.0.window_handle()
+    }
+}
+
+/// An object that provides both a window handle and the display handle it belongs to.
+///
+/// This is automatically implemented for every type that implements both [`HasWindowHandle`] and
+/// [`HasDisplayHandle`], and allows APIs that need both handles (such as surface creation) to take
+/// a single bound.
+pub trait HasWindowAndDisplayHandle: HasWindowHandle + HasDisplayHandle {
+    /// Get both the window handle and the display handle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use raw_window_handle::{HandleError, HasWindowAndDisplayHandle};
+    /// fn create_surface(window: &impl HasWindowAndDisplayHandle) -> Result<(), HandleError> {
+    ///     let (window, display) = window.handles()?;
+    ///     // Create a surface from the handles here.
+    /// #   let _ = (window, display);
+    ///     Ok(())
+    /// }
+    /// ```
+    fn handles(&self) -> Result<(WindowHandle<'_>, DisplayHandle<'_>), HandleError> {
+        Ok((self.window_handle()?, self.display_handle()?))
+    }
+}
+
+impl<H: HasWindowHandle + HasDisplayHandle + ?Sized> HasWindowAndDisplayHandle for H {}
+
+/// Get the window handle of `window` as a specific platform's handle type.
+///
+/// This combines [`HasWindowHandle::window_handle`] with the `TryFrom<RawWindowHandle>`
+/// conversion of the handle type. If the window handle is of a different kind than `T`,
+/// [`HandleError::NotSupported`] is returned.
+///
+/// # Example
+///
+/// ```
+/// # use raw_window_handle::{window_handle_as, HandleError, HasWindowHandle, Win32WindowHandle};
+/// fn create_surface(window: &dyn HasWindowHandle) -> Result<(), HandleError> {
+///     let handle: Win32WindowHandle = window_handle_as(window)?;
+///     // Create a surface from the HWND here.
+/// #   let _ = handle;
+///     Ok(())
+/// }
+/// ```
+pub fn window_handle_as<T, H>(window: &H) -> Result<T, HandleError>
+where
+    T: TryFrom<RawWindowHandle>,
+    H: HasWindowHandle + ?Sized,
+{
+    T::try_from(window.window_handle()?.as_raw()).map_err(|_| HandleError::NotSupported)
+}
+
 /// The handle to a window.
 ///
 /// This is the primary return type of the [`HasWindowHandle`] trait. All *pointers* within this type
@@ -244,6 +560,44 @@ impl<'a> WindowHandle<'a> {
     /// It is *also* possible for the window to be replaced with another, valid-but-different window. User
     /// code should be aware of this possibility, and should be ready to soundly handle the possible error
     /// conditions that can arise from this.
+    ///
+    /// # Example
+    ///
+    /// Since `WindowHandle` implements [`HasWindowHandle`] itself (and likewise for
+    /// [`DisplayHandle`] and [`HasDisplayHandle`]), this can be used to bridge handles received
+    /// over FFI to APIs that are generic over these traits, without having to write a wrapper
+    /// type.
+    ///
+    /// ```
+    /// # use core::ffi::{c_ulong, c_void};
+    /// # use core::marker::PhantomData;
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::{
+    /// #     DisplayHandle, HasDisplayHandle, HasWindowHandle, WindowHandle, XlibDisplayHandle,
+    /// #     XlibWindowHandle,
+    /// # };
+    /// # fn render(window: impl HasWindowHandle + HasDisplayHandle) { let _ = window; }
+    /// /// A window and display that the C library promises to keep alive for `'a`.
+    /// struct FfiWindow<'a> {
+    ///     display: *mut c_void,
+    ///     window: c_ulong,
+    ///     _marker: PhantomData<&'a ()>,
+    /// }
+    ///
+    /// fn render_ffi_window(ffi: &FfiWindow<'_>) {
+    ///     let window = XlibWindowHandle::new(ffi.window).into();
+    ///     let display = XlibDisplayHandle::new(NonNull::new(ffi.display), 0).into();
+    ///     // SAFETY: The C library keeps the window and display alive while `ffi` is borrowed.
+    ///     let window = unsafe { WindowHandle::borrow_raw(window) };
+    ///     let display = unsafe { DisplayHandle::borrow_raw(display) };
+    ///     render((window, display));
+    /// }
+    /// # render_ffi_window(&FfiWindow {
+    /// #     display: NonNull::dangling().as_ptr(),
+    /// #     window: 1,
+    /// #     _marker: PhantomData,
+    /// # });
+    /// ```
     pub unsafe fn borrow_raw(raw: RawWindowHandle) -> Self {
         Self {
             raw,
@@ -251,9 +605,106 @@ impl<'a> WindowHandle<'a> {
         }
     }
 
+    /// Borrow a `WindowHandle` and the [`DisplayHandle`] it belongs to, with the same lifetime.
+    ///
+    /// # Safety
+    ///
+    /// The requirements of both [`WindowHandle::borrow_raw`] and [`DisplayHandle::borrow_raw`]
+    /// must be upheld.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::num::NonZeroIsize;
+    /// # use raw_window_handle::{Win32WindowHandle, WindowHandle, WindowsDisplayHandle};
+    /// let hwnd: NonZeroIsize;
+    /// # hwnd = NonZeroIsize::new(1).unwrap();
+    /// let window = Win32WindowHandle::new(hwnd).into();
+    /// let display = WindowsDisplayHandle::new().into();
+    /// // SAFETY: The HWND is valid for as long as the handles are used.
+    /// let (window, display) = unsafe { WindowHandle::borrow_raw_with_display(window, display) };
+    /// # let _ = (window, display);
+    /// ```
+    pub unsafe fn borrow_raw_with_display(
+        window: RawWindowHandle,
+        display: RawDisplayHandle,
+    ) -> (Self, DisplayHandle<'a>) {
+        // SAFETY: Upheld by the caller.
+        unsafe { (Self::borrow_raw(window), DisplayHandle::borrow_raw(display)) }
+    }
+
     /// Get the underlying raw window handle.
+    #[inline]
     pub fn as_raw(&self) -> RawWindowHandle {
-        self.raw.clone()
+        self.raw
+    }
+
+    /// Get the display that is implied by this window, with the same lifetime.
+    ///
+    /// This returns the empty display handle for platforms that only have a single display, such
+    /// as Windows, AppKit or the Web, and `None` for platforms where the display is a connection
+    /// that cannot be derived from the window. See [`RawWindowHandle::default_display`] for
+    /// details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use raw_window_handle::{DisplayHandle, RawWindowHandle, WebWindowHandle, WindowHandle};
+    /// # let raw = RawWindowHandle::from(WebWindowHandle::new(1));
+    /// let window: WindowHandle<'_>;
+    /// # window = unsafe { WindowHandle::borrow_raw(raw) };
+    /// assert_eq!(window.implied_display(), Some(DisplayHandle::web()));
+    /// ```
+    pub fn implied_display(&self) -> Option<DisplayHandle<'a>> {
+        let display = self.raw.default_display()?;
+        // SAFETY: The implied display handles are empty, and do not borrow any data.
+        Some(unsafe { DisplayHandle::borrow_raw(display) })
+    }
+
+    /// Transform the underlying raw window handle, keeping the same lifetime.
+    ///
+    /// This can be useful for adapter layers, for example to point a parent window's handle at a
+    /// child view instead.
+    ///
+    /// # Safety
+    ///
+    /// The raw window handle returned by `f` must uphold the same requirements as
+    /// [`WindowHandle::borrow_raw`], for the entire lifetime `'a`.
+    pub unsafe fn map_raw(self, f: impl FnOnce(RawWindowHandle) -> RawWindowHandle) -> Self {
+        // SAFETY: Upheld by the caller.
+        unsafe { Self::borrow_raw(f(self.raw)) }
+    }
+
+    /// Extend the lifetime of the window handle to `'static`.
+    ///
+    /// This may be necessary to store the handle in a global registry, such as one that is
+    /// accessed from FFI callbacks.
+    ///
+    /// # Safety
+    ///
+    /// The window that this handle refers to must stay alive, and must remain valid in the sense
+    /// of [`WindowHandle::borrow_raw`], for as long as the returned handle or any copy of it is
+    /// used. In practice, this means that the window must outlive every use of the handle until
+    /// the program exits, or that the handle must be removed from wherever it was stored before
+    /// the window is destroyed.
+    ///
+    /// The borrow checker can no longer detect use of the handle after the window was destroyed,
+    /// so prefer keeping the original lifetime whenever possible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use raw_window_handle::{RawWindowHandle, WebWindowHandle, WindowHandle};
+    /// fn register(window: WindowHandle<'_>) -> WindowHandle<'static> {
+    ///     // SAFETY: The window is never destroyed while the program is running.
+    ///     unsafe { window.into_static() }
+    /// }
+    /// # let raw = RawWindowHandle::from(WebWindowHandle::new(1));
+    /// # let _ = register(unsafe { WindowHandle::borrow_raw(raw) });
+    /// ```
+    pub unsafe fn into_static(self) -> WindowHandle<'static> {
+        // SAFETY: Upheld by the caller.
+        unsafe { WindowHandle::borrow_raw(self.raw) }
     }
 }
 
@@ -275,8 +726,72 @@ impl From<WindowHandle<'_>> for RawWindowHandle {
     }
 }
 
+impl PartialEq<RawWindowHandle> for WindowHandle<'_> {
+    fn eq(&self, other: &RawWindowHandle) -> bool {
+        self.raw == *other
+    }
+}
+
+impl PartialEq<WindowHandle<'_>> for RawWindowHandle {
+    fn eq(&self, other: &WindowHandle<'_>) -> bool {
+        *self == other.raw
+    }
+}
+
 impl HasWindowHandle for WindowHandle<'_> {
     fn window_handle(&self) -> Result<Self, HandleError> {
         Ok(*self)
     }
 }
+
+macro_rules! window_handle_accessors {
+    ($($fn:ident, $variant:ident, $ty:ident;)*) => {
+        impl WindowHandle<'_> {
+            $(
+                #[doc = concat!(
+                    "Get the [`", stringify!($ty), "`](crate::", stringify!($ty), ") if this is a ",
+                    "[`RawWindowHandle::", stringify!($variant), "`] handle.",
+                )]
+                pub fn $fn(&self) -> Option<crate::$ty> {
+                    match self.raw {
+                        RawWindowHandle::$variant(handle) => Some(handle),
+                        _ => None,
+                    }
+                }
+            )*
+        }
+    };
+}
+
+window_handle_accessors! {
+    as_uikit, UiKit, UiKitWindowHandle;
+    as_appkit, AppKit, AppKitWindowHandle;
+    as_orbital, Orbital, OrbitalWindowHandle;
+    as_ohos_ndk, OhosNdk, OhosNdkWindowHandle;
+    as_xlib, Xlib, XlibWindowHandle;
+    as_xcb, Xcb, XcbWindowHandle;
+    as_wayland, Wayland, WaylandWindowHandle;
+    as_drm, Drm, DrmWindowHandle;
+    as_gbm, Gbm, GbmWindowHandle;
+    as_win32, Win32, Win32WindowHandle;
+    as_winrt, WinRt, WinRtWindowHandle;
+    as_web, Web, WebWindowHandle;
+    as_web_canvas, WebCanvas, WebCanvasWindowHandle;
+    as_web_offscreen_canvas, WebOffscreenCanvas, WebOffscreenCanvasWindowHandle;
+    as_android_ndk, AndroidNdk, AndroidNdkWindowHandle;
+    as_haiku, Haiku, HaikuWindowHandle;
+    as_screen, Screen, ScreenWindowHandle;
+    as_wayland_subsurface, WaylandSubsurface, WaylandSubsurfaceWindowHandle;
+    as_switch, Switch, SwitchWindowHandle;
+    as_appkit_metal_layer, AppKitMetalLayer, AppKitMetalLayerWindowHandle;
+    as_uikit_metal_layer, UiKitMetalLayer, UiKitMetalLayerWindowHandle;
+    as_web_canvas_selector, WebCanvasSelector, WebCanvasSelectorWindowHandle;
+    as_fuchsia, Fuchsia, FuchsiaViewWindowHandle;
+    as_win_ui, WinUi, WinUiSwapChainPanelWindowHandle;
+    as_dma_buf, DmaBuf, DmaBufWindowHandle;
+    as_null, Null, NullWindowHandle;
+    as_play_station, PlayStation, PlayStationWindowHandle;
+    as_custom, Custom, CustomWindowHandle;
+    as_tizen, Tizen, TizenWindowHandle;
+    as_sdl, Sdl, SdlMetalWindowHandle;
+}
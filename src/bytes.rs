@@ -0,0 +1,503 @@
+//! A fixed-size byte encoding of [`RawWindowHandle`].
+
+use core::num::{NonZeroIsize, NonZeroU32};
+use core::ptr::NonNull;
+
+use crate::{
+    AndroidNdkWindowHandle, AppKitMetalLayerWindowHandle, AppKitWindowHandle, CustomWindowHandle,
+    DmaBufWindowHandle, DrmWindowHandle, FuchsiaViewWindowHandle, GbmWindowHandle,
+    HaikuWindowHandle, HandleError, NullWindowHandle, OhosNdkWindowHandle, OrbitalWindowHandle,
+    PlayStationWindowHandle, RawWindowHandle, RawWindowHandleKind, ScreenWindowHandle,
+    SdlMetalWindowHandle, SwitchWindowHandle, TizenWindowHandle, UiKitLayerKind,
+    UiKitMetalLayerWindowHandle, UiKitWindowHandle, WaylandSubsurfaceWindowHandle,
+    WaylandWindowHandle, WebCanvasSelectorWindowHandle, WebCanvasWindowHandle,
+    WebOffscreenCanvasWindowHandle, WebWindowHandle, Win32WindowHandle, WinRtWindowHandle,
+    WinUiSwapChainPanelWindowHandle, XcbWindowHandle, XlibWindowHandle,
+};
+
+/// The version of the encoding, stored in the first byte.
+const VERSION: u8 = 1;
+
+struct Writer {
+    bytes: [u8; RawWindowHandle::ENCODED_LEN],
+    pos: usize,
+}
+
+impl Writer {
+    fn write(&mut self, bytes: &[u8]) {
+        self.bytes[self.pos..self.pos + bytes.len()].copy_from_slice(bytes);
+        self.pos += bytes.len();
+    }
+
+    fn u8(&mut self, value: u8) {
+        self.write(&[value]);
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.write(&value.to_ne_bytes());
+    }
+
+    fn i32(&mut self, value: i32) {
+        self.write(&value.to_ne_bytes());
+    }
+
+    fn u64(&mut self, value: u64) {
+        self.write(&value.to_ne_bytes());
+    }
+
+    fn ptr<T>(&mut self, ptr: NonNull<T>) {
+        self.u64(ptr.as_ptr() as usize as u64);
+    }
+
+    fn opt_ptr<T>(&mut self, ptr: Option<NonNull<T>>) {
+        self.u64(ptr.map_or(0, |ptr| ptr.as_ptr() as usize as u64));
+    }
+
+    fn isize(&mut self, value: Option<NonZeroIsize>) {
+        self.u64(value.map_or(0, |value| value.get() as i64 as u64));
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl Reader<'_> {
+    fn read<const N: usize>(&mut self) -> [u8; N] {
+        let (head, tail) = self.bytes.split_at(N);
+        self.bytes = tail;
+        let mut array = [0; N];
+        array.copy_from_slice(head);
+        array
+    }
+
+    fn u8(&mut self) -> u8 {
+        u8::from_ne_bytes(self.read())
+    }
+
+    fn u32(&mut self) -> u32 {
+        u32::from_ne_bytes(self.read())
+    }
+
+    fn i32(&mut self) -> i32 {
+        i32::from_ne_bytes(self.read())
+    }
+
+    fn u64(&mut self) -> u64 {
+        u64::from_ne_bytes(self.read())
+    }
+
+    fn opt_ptr<T>(&mut self) -> Option<NonNull<T>> {
+        NonNull::new(self.u64() as usize as *mut T)
+    }
+
+    fn ptr<T>(&mut self) -> Result<NonNull<T>, HandleError> {
+        self.opt_ptr().ok_or(HandleError::NotSupported)
+    }
+
+    fn isize(&mut self) -> Option<NonZeroIsize> {
+        NonZeroIsize::new(self.u64() as i64 as isize)
+    }
+}
+
+impl RawWindowHandle {
+    /// The length of the encoding produced by [`to_bytes`][Self::to_bytes].
+    pub const ENCODED_LEN: usize = 40;
+
+    /// Encode the handle into a fixed-size array of bytes.
+    ///
+    /// This is a compact, dependency-free encoding intended for transporting handles between
+    /// processes on the same machine, for example through shared memory. The first byte is the
+    /// version of the encoding, and the second byte is the index of the handle's
+    /// [kind][Self::kind] in [`RawWindowHandleKind::ALL`]. The fields of the handle follow in
+    /// declaration order, in native byte order, with pointers and pointer-sized integers stored
+    /// as 64-bit values and `None` stored as `0`. The remaining bytes are zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use raw_window_handle::{RawWindowHandle, XlibWindowHandle};
+    /// let handle = RawWindowHandle::from(XlibWindowHandle::new(42).with_visual_id(7));
+    /// let bytes: [u8; RawWindowHandle::ENCODED_LEN] = handle.to_bytes();
+    /// assert_eq!(RawWindowHandle::from_bytes(bytes).ok(), Some(handle));
+    /// ```
+    // `c_ulong` is not `u64` on all platforms.
+    #[allow(clippy::useless_conversion)]
+    pub fn to_bytes(&self) -> [u8; Self::ENCODED_LEN] {
+        let mut w = Writer {
+            bytes: [0; Self::ENCODED_LEN],
+            pos: 0,
+        };
+        w.u8(VERSION);
+        w.u8(self.kind() as u8);
+        match self {
+            Self::UiKit(h) => {
+                w.ptr(h.ui_view);
+                w.opt_ptr(h.ui_view_controller);
+                w.u8(match h.layer_kind {
+                    UiKitLayerKind::Unknown => 0,
+                    UiKitLayerKind::Eagl => 1,
+                    UiKitLayerKind::Metal => 2,
+                });
+            }
+            Self::AppKit(h) => {
+                w.ptr(h.ns_view);
+                w.opt_ptr(h.ns_window);
+                w.u8(h.wants_layer as u8);
+            }
+            Self::Orbital(h) => w.ptr(h.window),
+            Self::OhosNdk(h) => w.ptr(h.native_window),
+            Self::Xlib(h) => {
+                w.u64(u64::from(h.window));
+                w.u64(u64::from(h.visual_id));
+                w.i32(h.screen);
+                w.u8(h.depth);
+            }
+            Self::Xcb(h) => {
+                w.u32(h.window.get());
+                w.u32(h.visual_id.map_or(0, NonZeroU32::get));
+                w.i32(h.screen);
+                w.u8(h.depth);
+            }
+            Self::Wayland(h) => {
+                w.ptr(h.surface);
+                w.i32(h.buffer_scale);
+                w.opt_ptr(h.viewport);
+                w.opt_ptr(h.xdg_surface);
+                w.opt_ptr(h.xdg_toplevel);
+            }
+            Self::Drm(h) => {
+                w.u32(h.plane);
+                w.u32(h.connector_id);
+                w.u32(h.crtc_id);
+            }
+            Self::Gbm(h) => {
+                w.ptr(h.gbm_surface);
+                w.u32(h.format);
+                w.u64(h.modifier);
+            }
+            Self::Win32(h) => {
+                w.isize(Some(h.hwnd));
+                w.isize(h.hinstance);
+                w.isize(h.hdc);
+                w.isize(h.parent_hwnd);
+            }
+            Self::WinRt(h) => w.ptr(h.core_window),
+            Self::Web(h) => {
+                w.u32(h.id);
+                w.u64(h.scale_factor.to_bits());
+            }
+            Self::WebCanvas(h) => w.ptr(h.obj),
+            Self::WebOffscreenCanvas(h) => {
+                w.ptr(h.obj);
+                w.opt_ptr(h.message_port);
+            }
+            Self::AndroidNdk(h) => {
+                w.ptr(h.a_native_window);
+                w.opt_ptr(h.jvm);
+                w.opt_ptr(h.activity);
+            }
+            Self::Haiku(h) => {
+                w.ptr(h.b_window);
+                w.opt_ptr(h.b_direct_window);
+            }
+            Self::Screen(h) => {
+                w.ptr(h.window);
+                w.ptr(h.context);
+            }
+            Self::WaylandSubsurface(h) => {
+                w.ptr(h.surface);
+                w.ptr(h.subsurface);
+                w.ptr(h.parent);
+            }
+            Self::Switch(h) => w.ptr(h.nwindow),
+            Self::AppKitMetalLayer(h) => w.ptr(h.layer),
+            Self::UiKitMetalLayer(h) => w.ptr(h.layer),
+            Self::WebCanvasSelector(h) => {
+                w.ptr(h.selector);
+                w.u64(h.len as u64);
+            }
+            Self::Fuchsia(h) => w.ptr(h.view_ref),
+            Self::WinUi(h) => w.ptr(h.panel),
+            Self::DmaBuf(h) => {
+                w.i32(h.fd);
+                w.u32(h.format);
+                w.u64(h.modifier);
+                w.u32(h.width);
+                w.u32(h.height);
+            }
+            Self::PlayStation(h) => {
+                w.i32(h.video_out_handle);
+                w.u32(h.display_index);
+            }
+            Self::Custom(h) => {
+                w.u32(h.vendor_id);
+                w.ptr(h.handle);
+            }
+            Self::Tizen(h) => {
+                w.ptr(h.evas_object);
+                w.opt_ptr(h.wl_surface);
+            }
+            Self::Sdl(h) => w.ptr(h.metal_view),
+            Self::Null(_) => {}
+        }
+        w.bytes
+    }
+
+    /// Decode a handle that was encoded with [`to_bytes`][Self::to_bytes].
+    ///
+    /// Returns [`HandleError::NotSupported`] if the version or the kind of handle is unknown, or
+    /// if a field that must be non-null or non-zero is zero.
+    ///
+    /// Pointers are **not** validated. The decoded handle is only meaningful in the process that
+    /// encoded it (or one that shares its address space), and it is up to the caller to ensure
+    /// that the objects it refers to are still alive before e.g. passing it to
+    /// [`WindowHandle::borrow_raw`][crate::WindowHandle::borrow_raw].
+    ///
+    /// See [`to_bytes`][Self::to_bytes] for an example.
+    pub fn from_bytes(bytes: [u8; Self::ENCODED_LEN]) -> Result<Self, HandleError> {
+        use RawWindowHandleKind as K;
+
+        if bytes[0] != VERSION {
+            return Err(HandleError::NotSupported);
+        }
+        let kind = *RawWindowHandleKind::ALL
+            .get(bytes[1] as usize)
+            .ok_or(HandleError::NotSupported)?;
+        let mut r = Reader { bytes: &bytes[2..] };
+        Ok(match kind {
+            K::UiKit => {
+                let mut h = UiKitWindowHandle::new(r.ptr()?);
+                h.ui_view_controller = r.opt_ptr();
+                h.layer_kind = match r.u8() {
+                    0 => UiKitLayerKind::Unknown,
+                    1 => UiKitLayerKind::Eagl,
+                    2 => UiKitLayerKind::Metal,
+                    _ => return Err(HandleError::NotSupported),
+                };
+                h.into()
+            }
+            K::AppKit => {
+                let mut h = AppKitWindowHandle::new(r.ptr()?);
+                h.ns_window = r.opt_ptr();
+                h.wants_layer = r.u8() != 0;
+                h.into()
+            }
+            K::Orbital => OrbitalWindowHandle::new(r.ptr()?).into(),
+            K::OhosNdk => OhosNdkWindowHandle::new(r.ptr()?).into(),
+            K::Xlib => {
+                let mut h = XlibWindowHandle::new(r.u64() as _);
+                h.visual_id = r.u64() as _;
+                h.screen = r.i32();
+                h.depth = r.u8();
+                h.into()
+            }
+            K::Xcb => {
+                let mut h = XcbWindowHandle::new(
+                    NonZeroU32::new(r.u32()).ok_or(HandleError::NotSupported)?,
+                );
+                h.visual_id = NonZeroU32::new(r.u32());
+                h.screen = r.i32();
+                h.depth = r.u8();
+                h.into()
+            }
+            K::Wayland => {
+                let mut h = WaylandWindowHandle::new(r.ptr()?);
+                h.buffer_scale = r.i32();
+                h.viewport = r.opt_ptr();
+                h.xdg_surface = r.opt_ptr();
+                h.xdg_toplevel = r.opt_ptr();
+                h.into()
+            }
+            K::Drm => {
+                let mut h = DrmWindowHandle::new(r.u32());
+                h.connector_id = r.u32();
+                h.crtc_id = r.u32();
+                h.into()
+            }
+            K::Gbm => {
+                let mut h = GbmWindowHandle::new(r.ptr()?);
+                h.format = r.u32();
+                h.modifier = r.u64();
+                h.into()
+            }
+            K::Win32 => {
+                let mut h = Win32WindowHandle::new(r.isize().ok_or(HandleError::NotSupported)?);
+                h.hinstance = r.isize();
+                h.hdc = r.isize();
+                h.parent_hwnd = r.isize();
+                h.into()
+            }
+            K::WinRt => WinRtWindowHandle::new(r.ptr()?).into(),
+            K::Web => {
+                let mut h = WebWindowHandle::new(r.u32());
+                h.scale_factor = f64::from_bits(r.u64());
+                h.into()
+            }
+            K::WebCanvas => WebCanvasWindowHandle::new(r.ptr()?).into(),
+            K::WebOffscreenCanvas => {
+                let mut h = WebOffscreenCanvasWindowHandle::new(r.ptr()?);
+                h.message_port = r.opt_ptr();
+                h.into()
+            }
+            K::AndroidNdk => {
+                let mut h = AndroidNdkWindowHandle::new(r.ptr()?);
+                h.jvm = r.opt_ptr();
+                h.activity = r.opt_ptr();
+                h.into()
+            }
+            K::Haiku => {
+                let mut h = HaikuWindowHandle::new(r.ptr()?);
+                h.b_direct_window = r.opt_ptr();
+                h.into()
+            }
+            K::Screen => ScreenWindowHandle::new(r.ptr()?, r.ptr()?).into(),
+            K::WaylandSubsurface => {
+                WaylandSubsurfaceWindowHandle::new(r.ptr()?, r.ptr()?, r.ptr()?).into()
+            }
+            K::Switch => SwitchWindowHandle::new(r.ptr()?).into(),
+            K::AppKitMetalLayer => AppKitMetalLayerWindowHandle::new(r.ptr()?).into(),
+            K::UiKitMetalLayer => UiKitMetalLayerWindowHandle::new(r.ptr()?).into(),
+            K::WebCanvasSelector => {
+                WebCanvasSelectorWindowHandle::new(r.ptr()?, r.u64() as usize).into()
+            }
+            K::Fuchsia => FuchsiaViewWindowHandle::new(r.ptr()?).into(),
+            K::WinUi => WinUiSwapChainPanelWindowHandle::new(r.ptr()?).into(),
+            K::DmaBuf => {
+                let fd = r.i32();
+                let format = r.u32();
+                let modifier = r.u64();
+                let width = r.u32();
+                let height = r.u32();
+                DmaBufWindowHandle::new(fd, width, height, format)
+                    .with_modifier(modifier)
+                    .into()
+            }
+            K::PlayStation => {
+                let mut h = PlayStationWindowHandle::new(r.i32());
+                h.display_index = r.u32();
+                h.into()
+            }
+            K::Custom => CustomWindowHandle::new(r.u32(), r.ptr()?).into(),
+            K::Tizen => {
+                let mut h = TizenWindowHandle::new(r.ptr()?);
+                h.wl_surface = r.opt_ptr();
+                h.into()
+            }
+            K::Sdl => SdlMetalWindowHandle::new(r.ptr()?).into(),
+            K::Null => NullWindowHandle::new().into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::num::{NonZeroIsize, NonZeroU32};
+    use core::ptr::NonNull;
+
+    use super::VERSION;
+    use crate::{
+        DmaBufWindowHandle, DrmWindowHandle, HandleError, RawWindowHandle, RawWindowHandleKind,
+        UiKitLayerKind, UiKitWindowHandle, WaylandWindowHandle, Win32WindowHandle, XcbWindowHandle,
+    };
+
+    fn ptr(addr: usize) -> NonNull<core::ffi::c_void> {
+        NonNull::new(addr as *mut _).unwrap()
+    }
+
+    fn round_trip(handle: impl Into<RawWindowHandle>) {
+        let handle = handle.into();
+        let bytes = handle.to_bytes();
+        assert_eq!(bytes[0], VERSION);
+        assert_eq!(RawWindowHandleKind::ALL[bytes[1] as usize], handle.kind());
+        assert_eq!(RawWindowHandle::from_bytes(bytes).ok(), Some(handle));
+    }
+
+    #[test]
+    fn round_trip_populated() {
+        round_trip(
+            DmaBufWindowHandle::new(5, 1920, 1080, 0x3432_5258)
+                .with_modifier(0x0100_0000_0000_0001),
+        );
+        round_trip(
+            Win32WindowHandle::new(NonZeroIsize::new(0x1234).unwrap())
+                .with_hinstance(NonZeroIsize::new(0x40_0000))
+                .with_hdc(NonZeroIsize::new(-0x5678))
+                .with_parent(NonZeroIsize::new(0x9abc)),
+        );
+        round_trip(
+            WaylandWindowHandle::new(ptr(0x1000))
+                .with_buffer_scale(2)
+                .with_viewport(Some(ptr(0x2000)))
+                .with_xdg_surface(Some(ptr(0x3000)))
+                .with_xdg_toplevel(Some(ptr(0x4000))),
+        );
+        round_trip(
+            XcbWindowHandle::new(NonZeroU32::new(7).unwrap())
+                .with_visual_id(NonZeroU32::new(33))
+                .with_screen(1)
+                .with_depth(24),
+        );
+        round_trip(DrmWindowHandle::new(31).with_connector(42).with_crtc(53));
+        round_trip(
+            UiKitWindowHandle::new(ptr(0x1000))
+                .with_ui_view_controller(Some(ptr(0x2000)))
+                .with_layer_kind(UiKitLayerKind::Metal),
+        );
+    }
+
+    #[test]
+    fn unknown_version() {
+        let mut bytes = RawWindowHandle::from(DrmWindowHandle::new(1)).to_bytes();
+        bytes[0] = VERSION + 1;
+        assert!(matches!(
+            RawWindowHandle::from_bytes(bytes),
+            Err(HandleError::NotSupported)
+        ));
+    }
+
+    #[test]
+    fn unknown_kind() {
+        let mut bytes = RawWindowHandle::from(DrmWindowHandle::new(1)).to_bytes();
+        bytes[1] = RawWindowHandleKind::ALL.len() as u8;
+        assert!(matches!(
+            RawWindowHandle::from_bytes(bytes),
+            Err(HandleError::NotSupported)
+        ));
+    }
+
+    #[test]
+    fn zero_required_fields() {
+        // The encoding has a fixed length, so a short message that was zero-padded to
+        // `ENCODED_LEN` shows up as required fields that are zero.
+        for kind in [
+            RawWindowHandleKind::Win32,
+            RawWindowHandleKind::Xcb,
+            RawWindowHandleKind::Wayland,
+            RawWindowHandleKind::UiKit,
+        ] {
+            let mut bytes = [0; RawWindowHandle::ENCODED_LEN];
+            bytes[0] = VERSION;
+            bytes[1] = kind as u8;
+            assert!(
+                matches!(
+                    RawWindowHandle::from_bytes(bytes),
+                    Err(HandleError::NotSupported)
+                ),
+                "{:?}",
+                kind
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_enum_value() {
+        let mut bytes = RawWindowHandle::from(UiKitWindowHandle::new(ptr(0x1000))).to_bytes();
+        // Version, kind, `ui_view` and `ui_view_controller` precede the layer kind.
+        bytes[2 + 8 + 8] = 3;
+        assert!(matches!(
+            RawWindowHandle::from_bytes(bytes),
+            Err(HandleError::NotSupported)
+        ));
+    }
+}
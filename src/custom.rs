@@ -0,0 +1,43 @@
+use core::ffi::c_void;
+use core::ptr::NonNull;
+
+/// Raw window handle for a windowing system that is not supported by this crate.
+///
+/// This is an escape hatch for experimental platforms and out-of-tree windowing systems. The
+/// meaning of [`handle`][Self::handle] is defined by the [`vendor_id`][Self::vendor_id], and
+/// consumers that don't recognize the vendor ID should treat the handle as unsupported.
+///
+/// Vendor IDs are not allocated by this crate, so pick an ID that is unlikely to collide with
+/// others, such as a hash of your crate's name.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CustomWindowHandle {
+    /// An ID that identifies the windowing system, and defines the meaning of `handle`.
+    pub vendor_id: u32,
+    /// A pointer to the window, whose type is defined by the windowing system.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::non_null"))]
+    pub handle: NonNull<c_void>,
+}
+
+impl CustomWindowHandle {
+    /// Create a new handle to a window of a custom windowing system.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::CustomWindowHandle;
+    /// # type MyWindow = ();
+    /// #
+    /// const MY_VENDOR_ID: u32 = 0x4d59_5753;
+    ///
+    /// let window: NonNull<MyWindow>;
+    /// # window = NonNull::from(&());
+    /// let handle = CustomWindowHandle::new(MY_VENDOR_ID, window.cast());
+    /// ```
+    pub fn new(vendor_id: u32, handle: NonNull<c_void>) -> Self {
+        Self { vendor_id, handle }
+    }
+}
@@ -0,0 +1,32 @@
+use core::ffi::c_void;
+use core::ptr::NonNull;
+
+/// Raw window handle for a Fuchsia Scenic/Flatland view.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FuchsiaViewWindowHandle {
+    /// A pointer to the view, such as a Flatland `ViewCreationToken` or a Scenic `ViewRef`.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::non_null"))]
+    pub view_ref: NonNull<c_void>,
+}
+
+impl FuchsiaViewWindowHandle {
+    /// Create a new handle to a view.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::FuchsiaViewWindowHandle;
+    /// # type ViewCreationToken = ();
+    /// #
+    /// let view_ref: NonNull<ViewCreationToken>;
+    /// # view_ref = NonNull::from(&());
+    /// let handle = FuchsiaViewWindowHandle::new(view_ref.cast());
+    /// ```
+    pub fn new(view_ref: NonNull<c_void>) -> Self {
+        Self { view_ref }
+    }
+}
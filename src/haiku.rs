@@ -5,7 +5,10 @@ use super::DisplayHandle;
 
 /// Raw display handle for Haiku.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[repr(C)]
 pub struct HaikuDisplayHandle {}
 
 impl HaikuDisplayHandle {
@@ -18,7 +21,7 @@ impl HaikuDisplayHandle {
     /// # use raw_window_handle::HaikuDisplayHandle;
     /// let handle = HaikuDisplayHandle::new();
     /// ```
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Self {}
     }
 }
@@ -45,11 +48,16 @@ impl DisplayHandle<'static> {
 
 /// Raw window handle for Haiku.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HaikuWindowHandle {
-    /// A pointer to a BWindow object
+    /// A pointer to a `BWindow` object.
+    ///
+    /// This is the window itself, not a view inside of it.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::non_null"))]
     pub b_window: NonNull<c_void>,
-    /// A pointer to a BDirectWindow object that might be null
+    /// A pointer to a `BDirectWindow` object, if the window is one.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::option_non_null"))]
     pub b_direct_window: Option<NonNull<c_void>>,
 }
 
@@ -66,9 +74,9 @@ impl HaikuWindowHandle {
     /// #
     /// let b_window: NonNull<BWindow>;
     /// # b_window = NonNull::from(&());
-    /// let mut handle = HaikuWindowHandle::new(b_window.cast());
-    /// // Optionally set `b_direct_window`.
-    /// handle.b_direct_window = None;
+    /// let handle = HaikuWindowHandle::new(b_window.cast())
+    ///     // Optionally set `b_direct_window`.
+    ///     .with_b_direct_window(None);
     /// ```
     pub fn new(b_window: NonNull<c_void>) -> Self {
         Self {
@@ -76,4 +84,12 @@ impl HaikuWindowHandle {
             b_direct_window: None,
         }
     }
+
+    /// Set the `BDirectWindow` of the window.
+    ///
+    /// See [`new`][Self::new] for an example.
+    pub fn with_b_direct_window(mut self, b_direct_window: Option<NonNull<c_void>>) -> Self {
+        self.b_direct_window = b_direct_window;
+        self
+    }
 }
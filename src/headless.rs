@@ -0,0 +1,48 @@
+use super::DisplayHandle;
+
+/// Raw display handle for headless rendering, without any display server.
+///
+/// This may be used for compute or offscreen rendering, to signal that no display server is
+/// available. No window handle belongs to this display, so code that creates surfaces should
+/// reject it.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[repr(C)]
+pub struct HeadlessDisplayHandle {}
+
+impl HeadlessDisplayHandle {
+    /// Create a new empty display handle.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use raw_window_handle::HeadlessDisplayHandle;
+    /// let handle = HeadlessDisplayHandle::new();
+    /// ```
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+impl DisplayHandle<'static> {
+    /// Create a headless display handle.
+    ///
+    /// As no data is borrowed by this handle, it is completely safe to create. This function
+    /// may be useful to windowing framework implementations that want to avoid unsafe code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use raw_window_handle::{DisplayHandle, HasDisplayHandle};
+    /// # fn do_something(rwh: impl HasDisplayHandle) { let _ = rwh; }
+    /// let handle = DisplayHandle::headless();
+    /// do_something(handle);
+    /// ```
+    pub fn headless() -> Self {
+        // SAFETY: No data is borrowed.
+        unsafe { Self::borrow_raw(HeadlessDisplayHandle::new().into()) }
+    }
+}
@@ -26,6 +26,15 @@
 //! Some windowing systems use a separate display handle for some operations. The display usually
 //! represents a connection to some display server, but it is not necessarily tied to a particular
 //! window. See [`RawDisplayHandle`] for more details.
+//!
+//! ## Serialization
+//!
+//! When the `serde` feature is enabled, the raw handle types implement `serde::Serialize` and
+//! `serde::Deserialize`. Pointer fields are serialized as their integer address.
+//!
+//! **Deserialized pointers are not validated in any way.** A pointer that was serialized in one
+//! process is almost certainly meaningless in another, and it is up to the user to ensure that any
+//! deserialized handle refers to a live object before passing it to code that will use it.
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
@@ -35,32 +44,84 @@ extern crate std;
 
 mod android;
 mod appkit;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 mod borrowed;
+mod bytes;
+mod custom;
+mod fuchsia;
 mod haiku;
+mod headless;
+mod null;
 mod ohos;
+mod parse;
+mod playstation;
+mod qnx;
 mod redox;
+#[cfg(feature = "raw-window-handle-0-5")]
+#[cfg_attr(docsrs, doc(cfg(feature = "raw-window-handle-0-5")))]
+pub mod rwh_05;
+mod sdl;
+mod send_sync;
+#[cfg(feature = "serde")]
+mod serde_ptr;
+mod switch;
+#[cfg(feature = "test-util")]
+mod test_util;
+mod tizen;
 mod uikit;
 mod unix;
+mod vulkan;
 mod web;
+#[cfg(feature = "alloc")]
+mod window_set;
 mod windows;
 
 pub use android::{AndroidDisplayHandle, AndroidNdkWindowHandle};
-pub use appkit::{AppKitDisplayHandle, AppKitWindowHandle};
-pub use borrowed::{DisplayHandle, HasDisplayHandle, HasWindowHandle, WindowHandle};
+pub use appkit::{AppKitDisplayHandle, AppKitMetalLayerWindowHandle, AppKitWindowHandle};
+#[cfg(feature = "alloc")]
+pub use borrowed::OwnedDisplayHandle;
+pub use borrowed::{
+    window_handle_as, DisplayHandle, FromRawDisplayHandle, HasDisplayHandle,
+    HasWindowAndDisplayHandle, HasWindowHandle, WindowHandle,
+};
+pub use custom::CustomWindowHandle;
+pub use fuchsia::FuchsiaViewWindowHandle;
 pub use haiku::{HaikuDisplayHandle, HaikuWindowHandle};
+pub use headless::HeadlessDisplayHandle;
+pub use null::NullWindowHandle;
 pub use ohos::{OhosDisplayHandle, OhosNdkWindowHandle};
+pub use parse::ParseKindError;
+pub use playstation::PlayStationWindowHandle;
+pub use qnx::{ScreenDisplayHandle, ScreenWindowHandle};
 pub use redox::{OrbitalDisplayHandle, OrbitalWindowHandle};
-pub use uikit::{UiKitDisplayHandle, UiKitWindowHandle};
+pub use sdl::SdlMetalWindowHandle;
+pub use send_sync::{SendSyncRawDisplayHandle, SendSyncRawWindowHandle};
+pub use switch::{SwitchDisplayHandle, SwitchWindowHandle};
+pub use tizen::TizenWindowHandle;
+pub use uikit::{
+    UiKitDisplayHandle, UiKitLayerKind, UiKitMetalLayerWindowHandle, UiKitWindowHandle,
+};
 pub use unix::{
-    DrmDisplayHandle, DrmWindowHandle, GbmDisplayHandle, GbmWindowHandle, WaylandDisplayHandle,
-    WaylandWindowHandle, XcbDisplayHandle, XcbWindowHandle, XlibDisplayHandle, XlibWindowHandle,
+    DmaBufWindowHandle, DrmDisplayHandle, DrmWindowHandle, GbmDisplayHandle, GbmWindowHandle,
+    WaylandDisplayHandle, WaylandSubsurfaceWindowHandle, WaylandWindowHandle, XcbDisplayHandle,
+    XcbWindowHandle, XlibDisplayHandle, XlibWindowHandle,
 };
+pub use vulkan::{required_surface_extensions, VulkanDisplayHandle};
 pub use web::{
-    WebCanvasWindowHandle, WebDisplayHandle, WebOffscreenCanvasWindowHandle, WebWindowHandle,
+    WebCanvasSelectorWindowHandle, WebCanvasWindowHandle, WebDisplayHandle,
+    WebOffscreenCanvasWindowHandle, WebWindowHandle, WebWorkerDisplayHandle,
+};
+#[cfg(feature = "alloc")]
+pub use window_set::WindowHandleSet;
+pub use windows::{
+    Win32WindowHandle, WinRtWindowHandle, WinUiSwapChainPanelWindowHandle, WindowsDisplayHandle,
 };
-pub use windows::{Win32WindowHandle, WinRtWindowHandle, WindowsDisplayHandle};
 
+use core::ffi::{c_int, c_void};
 use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ptr::NonNull;
 
 /// Window that wraps around a raw window handle.
 ///
@@ -109,7 +170,8 @@ unsafe impl<T: HasWindowHandle + ?Sized> HasRawWindowHandle for T {
 /// [`RawWindowHandle::Xlib`] on macOS, it would just be weird, and probably
 /// requires something like XQuartz be used).
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RawWindowHandle {
     /// A raw window handle for UIKit (Apple's non-macOS windowing library).
     ///
@@ -207,6 +269,757 @@ pub enum RawWindowHandle {
     /// ## Availability Hints
     /// This variant is used on HaikuOS.
     Haiku(HaikuWindowHandle),
+    /// A raw window handle for the QNX Screen Graphics Subsystem.
+    ///
+    /// ## Availability Hints
+    /// This variant is used on QNX Neutrino (`target_os = "nto"`).
+    Screen(ScreenWindowHandle),
+    /// A raw window handle for a Wayland subsurface.
+    ///
+    /// ## Availability Hints
+    /// This variant should be expected anywhere Wayland works, which is
+    /// currently some subset of unix systems.
+    WaylandSubsurface(WaylandSubsurfaceWindowHandle),
+    /// A raw window handle for the Nintendo Switch.
+    ///
+    /// ## Availability Hints
+    /// This variant is used on the Nintendo Switch (`target_os = "horizon"`).
+    Switch(SwitchWindowHandle),
+    /// A raw window handle for a `CAMetalLayer` in AppKit.
+    ///
+    /// ## Availability Hints
+    /// This variant is used on macOS, although Mac Catalyst can also use it
+    /// despite being `target_os = "ios"`.
+    AppKitMetalLayer(AppKitMetalLayerWindowHandle),
+    /// A raw window handle for a `CAMetalLayer` in UIKit.
+    ///
+    /// ## Availability Hints
+    /// This variant is used on iOS, tvOS, watchOS, visionOS, and Mac
+    /// Catalyst, as these are the targets that (currently) support UIKit.
+    UiKitMetalLayer(UiKitMetalLayerWindowHandle),
+    /// A raw window handle for a Web canvas identified by a CSS selector.
+    ///
+    /// ## Availability Hints
+    /// This variant is used on Wasm or asm.js targets when targeting the Web/HTML5
+    /// with Emscripten (`target_os = "emscripten"`).
+    WebCanvasSelector(WebCanvasSelectorWindowHandle),
+    /// A raw window handle for a Fuchsia Scenic/Flatland view.
+    ///
+    /// ## Availability Hints
+    /// This variant is used on Fuchsia (`target_os = "fuchsia"`).
+    Fuchsia(FuchsiaViewWindowHandle),
+    /// A raw window handle for a WinUI/UWP `SwapChainPanel`.
+    ///
+    /// ## Availability Hints
+    /// This variant is used on Windows systems.
+    WinUi(WinUiSwapChainPanelWindowHandle),
+    /// A raw window handle for a Linux dma-buf.
+    ///
+    /// ## Availability Hints
+    /// This variant is used on Linux when importing buffers without a windowing system, such as in
+    /// camera or video pipelines.
+    DmaBuf(DmaBufWindowHandle),
+    /// A placeholder for a window that has not been created yet.
+    ///
+    /// This variant does not refer to any window, see [`NullWindowHandle`].
+    Null(NullWindowHandle),
+    /// A raw window handle for the PlayStation 4 and PlayStation 5.
+    ///
+    /// ## Availability Hints
+    /// This variant is used on PlayStation console ports.
+    PlayStation(PlayStationWindowHandle),
+    /// A raw window handle for a windowing system that is not supported by this crate.
+    ///
+    /// ## Availability Hints
+    /// This variant may be used on any platform, but is only understood by consumers that recognize the
+    /// vendor ID of the handle.
+    Custom(CustomWindowHandle),
+    /// A raw window handle for Tizen, using EFL.
+    ///
+    /// ## Availability Hints
+    /// This variant is used on Tizen, which is a subset of Linux.
+    Tizen(TizenWindowHandle),
+    /// A raw window handle for an SDL Metal view.
+    ///
+    /// ## Availability Hints
+    /// This variant is used by applications using SDL on macOS and iOS.
+    Sdl(SdlMetalWindowHandle),
+}
+
+impl RawWindowHandle {
+    /// The size in bytes of the largest window handle, including the discriminant.
+    ///
+    /// This is the same as `size_of::<RawWindowHandle>()`, and may be used to size buffers that
+    /// handles are stored in. It is currently 48 bytes on 64-bit platforms, but may grow when new
+    /// variants or fields are added.
+    pub const fn max_size() -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    /// Get the kind of window handle, without its payload.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use raw_window_handle::{RawWindowHandle, RawWindowHandleKind, WebWindowHandle};
+    /// let handle = RawWindowHandle::from(WebWindowHandle::new(1));
+    /// assert_eq!(handle.kind(), RawWindowHandleKind::Web);
+    /// ```
+    pub fn kind(&self) -> RawWindowHandleKind {
+        match self {
+            Self::UiKit(_) => RawWindowHandleKind::UiKit,
+            Self::AppKit(_) => RawWindowHandleKind::AppKit,
+            Self::Orbital(_) => RawWindowHandleKind::Orbital,
+            Self::OhosNdk(_) => RawWindowHandleKind::OhosNdk,
+            Self::Xlib(_) => RawWindowHandleKind::Xlib,
+            Self::Xcb(_) => RawWindowHandleKind::Xcb,
+            Self::Wayland(_) => RawWindowHandleKind::Wayland,
+            Self::Drm(_) => RawWindowHandleKind::Drm,
+            Self::Gbm(_) => RawWindowHandleKind::Gbm,
+            Self::Win32(_) => RawWindowHandleKind::Win32,
+            Self::WinRt(_) => RawWindowHandleKind::WinRt,
+            Self::Web(_) => RawWindowHandleKind::Web,
+            Self::WebCanvas(_) => RawWindowHandleKind::WebCanvas,
+            Self::WebOffscreenCanvas(_) => RawWindowHandleKind::WebOffscreenCanvas,
+            Self::AndroidNdk(_) => RawWindowHandleKind::AndroidNdk,
+            Self::Haiku(_) => RawWindowHandleKind::Haiku,
+            Self::Screen(_) => RawWindowHandleKind::Screen,
+            Self::WaylandSubsurface(_) => RawWindowHandleKind::WaylandSubsurface,
+            Self::Switch(_) => RawWindowHandleKind::Switch,
+            Self::AppKitMetalLayer(_) => RawWindowHandleKind::AppKitMetalLayer,
+            Self::UiKitMetalLayer(_) => RawWindowHandleKind::UiKitMetalLayer,
+            Self::WebCanvasSelector(_) => RawWindowHandleKind::WebCanvasSelector,
+            Self::Fuchsia(_) => RawWindowHandleKind::Fuchsia,
+            Self::WinUi(_) => RawWindowHandleKind::WinUi,
+            Self::DmaBuf(_) => RawWindowHandleKind::DmaBuf,
+            Self::Null(_) => RawWindowHandleKind::Null,
+            Self::PlayStation(_) => RawWindowHandleKind::PlayStation,
+            Self::Custom(_) => RawWindowHandleKind::Custom,
+            Self::Tizen(_) => RawWindowHandleKind::Tizen,
+            Self::Sdl(_) => RawWindowHandleKind::Sdl,
+        }
+    }
+
+    /// Whether this window handle contains any pointer fields.
+    ///
+    /// Handles that only contain integers (such as X11 XIDs or the ID of a [`WebWindowHandle`])
+    /// may be easier to reason about when sending them to other threads, see
+    /// [`SendSyncRawWindowHandle`].
+    pub fn is_pointer_based(&self) -> bool {
+        match self {
+            Self::UiKit(_)
+            | Self::AppKit(_)
+            | Self::Orbital(_)
+            | Self::OhosNdk(_)
+            | Self::Wayland(_)
+            | Self::Gbm(_)
+            | Self::WinRt(_)
+            | Self::WebCanvas(_)
+            | Self::WebOffscreenCanvas(_)
+            | Self::AndroidNdk(_)
+            | Self::Haiku(_)
+            | Self::Screen(_)
+            | Self::WaylandSubsurface(_)
+            | Self::Switch(_)
+            | Self::AppKitMetalLayer(_)
+            | Self::UiKitMetalLayer(_)
+            | Self::WebCanvasSelector(_)
+            | Self::Fuchsia(_)
+            | Self::WinUi(_)
+            | Self::Custom(_)
+            | Self::Tizen(_)
+            | Self::Sdl(_) => true,
+            Self::Xlib(_)
+            | Self::Xcb(_)
+            | Self::Drm(_)
+            | Self::Win32(_)
+            | Self::Web(_)
+            | Self::DmaBuf(_)
+            | Self::Null(_)
+            | Self::PlayStation(_) => false,
+        }
+    }
+
+    /// Whether this is a [`Null`][Self::Null] handle, which does not refer to any window.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use raw_window_handle::{NullWindowHandle, RawWindowHandle, WebWindowHandle};
+    /// assert!(RawWindowHandle::from(NullWindowHandle::new()).is_null());
+    /// assert!(!RawWindowHandle::from(WebWindowHandle::new(1)).is_null());
+    /// ```
+    pub fn is_null(&self) -> bool {
+        matches!(self, Self::Null(_))
+    }
+
+    /// Get a cheap identity of the window that this handle refers to.
+    ///
+    /// This combines the primary pointer or ID of the handle (such as the `HWND`, the `wl_surface`
+    /// or the canvas ID) with the [kind][Self::kind] of handle in the upper 8 bits. Two handles to
+    /// the same window will have the same identity, which makes it useful as a key for caching
+    /// e.g. GPU surfaces.
+    ///
+    /// This is a best-effort identity, and is not a safety-relevant guarantee. Only the lower
+    /// 56 bits of the pointer or ID are used, and the identity may change between versions of this
+    /// crate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use raw_window_handle::{RawWindowHandle, WebWindowHandle};
+    /// let a = RawWindowHandle::from(WebWindowHandle::new(1));
+    /// let b = RawWindowHandle::from(WebWindowHandle::new(1));
+    /// assert_eq!(a.identity(), b.identity());
+    /// ```
+    pub fn identity(&self) -> u64 {
+        ((self.kind() as u64) << 56) | (self.primary_value() & ((1 << 56) - 1))
+    }
+
+    /// Hash the logical identity of the window that this handle refers to.
+    ///
+    /// The [`Hash`] implementation of `RawWindowHandle` hashes every field of the handle, so two
+    /// handles to the same window may hash differently if one of them has more optional fields
+    /// filled in. This instead only hashes the [kind][Self::kind] of handle and its primary pointer
+    /// or ID, the same values that [`identity`][Self::identity] is derived from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::hash::{BuildHasher, Hasher};
+    /// # use std::collections::hash_map::RandomState;
+    /// # use raw_window_handle::{RawWindowHandle, XlibWindowHandle};
+    /// let a = RawWindowHandle::from(XlibWindowHandle::new(1));
+    /// let b = RawWindowHandle::from(XlibWindowHandle::new(1).with_visual_id(2));
+    ///
+    /// let state = RandomState::new();
+    /// let (mut hash_a, mut hash_b) = (state.build_hasher(), state.build_hasher());
+    /// a.hash_identity(&mut hash_a);
+    /// b.hash_identity(&mut hash_b);
+    /// assert_eq!(hash_a.finish(), hash_b.finish());
+    /// ```
+    pub fn hash_identity<H: Hasher>(&self, state: &mut H) {
+        self.kind().hash(state);
+        self.primary_value().hash(state);
+    }
+
+    /// Get the primary pointer of the handle, if it is [pointer-based][Self::is_pointer_based].
+    ///
+    /// The pointer that is returned for each variant is:
+    ///
+    /// | Variant                                 | Field                                     |
+    /// | --------------------------------------- | ----------------------------------------- |
+    /// | `UiKit`                                 | `ui_view`                                 |
+    /// | `AppKit`                                | `ns_view`                                 |
+    /// | `Orbital`, `Screen`                     | `window`                                  |
+    /// | `OhosNdk`                               | `native_window`                           |
+    /// | `Wayland`, `WaylandSubsurface`          | `surface`                                 |
+    /// | `Gbm`                                   | `gbm_surface`                             |
+    /// | `WinRt`                                 | `core_window`                             |
+    /// | `WebCanvas`, `WebOffscreenCanvas`       | `obj`                                     |
+    /// | `AndroidNdk`                            | `a_native_window`                         |
+    /// | `Haiku`                                 | `b_window`                                |
+    /// | `Switch`                                | `nwindow`                                 |
+    /// | `AppKitMetalLayer`, `UiKitMetalLayer`   | `layer`                                   |
+    /// | `WebCanvasSelector`                     | `selector`                                |
+    /// | `Fuchsia`                               | `view_ref`                                |
+    /// | `WinUi`                                 | `panel`                                   |
+    /// | `Custom`                                | `handle`                                  |
+    /// | `Tizen`                                 | `evas_object`                             |
+    /// | `Sdl`                                   | `metal_view`                              |
+    ///
+    /// `Xlib`, `Xcb`, `Drm`, `Win32`, `Web`, `DmaBuf` and `PlayStation` handles are identified by
+    /// an integer, and return `None`. Use [`Win32WindowHandle::hwnd_ptr`] to get the `HWND` as a pointer. `Null`
+    /// handles do not refer to any window, and also return `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::{RawWindowHandle, WaylandWindowHandle, WebWindowHandle};
+    /// let surface = NonNull::dangling();
+    /// let handle = RawWindowHandle::from(WaylandWindowHandle::new(surface));
+    /// assert_eq!(handle.primary_pointer(), Some(surface));
+    ///
+    /// let handle = RawWindowHandle::from(WebWindowHandle::new(1));
+    /// assert_eq!(handle.primary_pointer(), None);
+    /// ```
+    pub fn primary_pointer(&self) -> Option<NonNull<c_void>> {
+        Some(match self {
+            Self::UiKit(h) => h.ui_view,
+            Self::AppKit(h) => h.ns_view,
+            Self::Orbital(h) => h.window,
+            Self::OhosNdk(h) => h.native_window,
+            Self::Wayland(h) => h.surface,
+            Self::Gbm(h) => h.gbm_surface,
+            Self::WinRt(h) => h.core_window,
+            Self::WebCanvas(h) => h.obj,
+            Self::WebOffscreenCanvas(h) => h.obj,
+            Self::AndroidNdk(h) => h.a_native_window,
+            Self::Haiku(h) => h.b_window,
+            Self::Screen(h) => h.window,
+            Self::WaylandSubsurface(h) => h.surface,
+            Self::Switch(h) => h.nwindow,
+            Self::AppKitMetalLayer(h) => h.layer,
+            Self::UiKitMetalLayer(h) => h.layer,
+            Self::WebCanvasSelector(h) => h.selector.cast(),
+            Self::Fuchsia(h) => h.view_ref,
+            Self::WinUi(h) => h.panel,
+            Self::Custom(h) => h.handle,
+            Self::Tizen(h) => h.evas_object,
+            Self::Sdl(h) => h.metal_view,
+            Self::Xlib(_)
+            | Self::Xcb(_)
+            | Self::Drm(_)
+            | Self::Win32(_)
+            | Self::Web(_)
+            | Self::DmaBuf(_)
+            | Self::PlayStation(_)
+            | Self::Null(_) => return None,
+        })
+    }
+
+    /// Check that the required IDs of the handle are not set to an obviously invalid value.
+    ///
+    /// This is a cheap, best-effort check that catches handles that were created with placeholder
+    /// values, and returns [`HandleError::Unavailable`] if:
+    ///
+    /// - The `window` of an Xlib handle is 0 (`None`).
+    /// - The `plane` of a DRM handle is 0, since DRM object IDs start at 1.
+    /// - The `fd` of a dma-buf handle is negative.
+    /// - The `video_out_handle` of a PlayStation handle is negative, which indicates an error code.
+    /// - The handle is [`Null`][Self::Null].
+    ///
+    /// Pointer fields are guaranteed to be non-null by their type, and there is no way to check
+    /// that they actually point to a live object, so they are not checked.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use raw_window_handle::{RawWindowHandle, XlibWindowHandle};
+    /// assert!(RawWindowHandle::from(XlibWindowHandle::new(1)).validate().is_ok());
+    /// assert!(RawWindowHandle::from(XlibWindowHandle::new(0)).validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), HandleError> {
+        let valid = match self {
+            Self::Xlib(h) => h.window != 0,
+            Self::Drm(h) => h.plane != 0,
+            Self::DmaBuf(h) => h.fd >= 0,
+            Self::PlayStation(h) => h.video_out_handle >= 0,
+            Self::Null(_) => false,
+            _ => true,
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(HandleError::Unavailable)
+        }
+    }
+
+    /// Get the display handle that is implied by this window handle, if it carries no data.
+    ///
+    /// On platforms where the display handle is empty, such as Windows, AppKit or the Web, there
+    /// is only a single display, and the window handle is enough to determine it. This returns the
+    /// empty display handle for those window handles.
+    ///
+    /// `None` is returned for backends where the display represents a connection, such as X11,
+    /// Wayland, DRM/GBM and QNX Screen, since the connection cannot be recovered from the window
+    /// alone. It is also returned for [`WebOffscreenCanvas`][Self::WebOffscreenCanvas], which
+    /// may be used with either a [`Web`][RawDisplayHandle::Web] or a
+    /// [`WebWorker`][RawDisplayHandle::WebWorker] display, for [`Sdl`][Self::Sdl], which may be
+    /// used on either macOS or iOS, and for window handles that do not have a corresponding
+    /// display handle at all, such as [`Fuchsia`][Self::Fuchsia],
+    /// [`PlayStation`][Self::PlayStation], [`Custom`][Self::Custom] and [`Null`][Self::Null].
+    ///
+    /// The returned display is guaranteed to be [compatible][backends_match] with this window.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::num::NonZeroIsize;
+    /// # use raw_window_handle::{
+    /// #     RawDisplayHandle, RawWindowHandle, Win32WindowHandle, WindowsDisplayHandle,
+    /// #     XlibWindowHandle,
+    /// # };
+    /// let window = RawWindowHandle::from(Win32WindowHandle::new(NonZeroIsize::new(1).unwrap()));
+    /// assert_eq!(
+    ///     window.default_display(),
+    ///     Some(RawDisplayHandle::from(WindowsDisplayHandle::new()))
+    /// );
+    ///
+    /// let window = RawWindowHandle::from(XlibWindowHandle::new(1));
+    /// assert_eq!(window.default_display(), None);
+    /// ```
+    pub fn default_display(&self) -> Option<RawDisplayHandle> {
+        Some(match self {
+            Self::UiKit(_) | Self::UiKitMetalLayer(_) => UiKitDisplayHandle::new().into(),
+            Self::AppKit(_) | Self::AppKitMetalLayer(_) => AppKitDisplayHandle::new().into(),
+            Self::Orbital(_) => OrbitalDisplayHandle::new().into(),
+            Self::OhosNdk(_) => OhosDisplayHandle::new().into(),
+            Self::Win32(_) | Self::WinRt(_) | Self::WinUi(_) => WindowsDisplayHandle::new().into(),
+            Self::Web(_) | Self::WebCanvas(_) | Self::WebCanvasSelector(_) => {
+                WebDisplayHandle::new().into()
+            }
+            Self::AndroidNdk(_) => AndroidDisplayHandle::new().into(),
+            Self::Haiku(_) => HaikuDisplayHandle::new().into(),
+            Self::Switch(_) => SwitchDisplayHandle::new().into(),
+            Self::Xlib(_)
+            | Self::Xcb(_)
+            | Self::Wayland(_)
+            | Self::WaylandSubsurface(_)
+            | Self::Tizen(_)
+            | Self::Drm(_)
+            | Self::Gbm(_)
+            | Self::DmaBuf(_)
+            | Self::Screen(_)
+            | Self::WebOffscreenCanvas(_)
+            | Self::Fuchsia(_)
+            | Self::PlayStation(_)
+            | Self::Custom(_)
+            | Self::Sdl(_)
+            | Self::Null(_) => return None,
+        })
+    }
+
+    /// Flatten the handle into a variant tag and up to two primary field values.
+    ///
+    /// This is intended for FFI bridges that need to store a handle in a C struct, without
+    /// depending on the layout of this enum. The tag is the index of the variant's
+    /// [kind][Self::kind] in [`RawWindowHandleKind::ALL`], and is stable: new variants are only
+    /// ever added with a new tag. Pointers are stored as their address, and `None` or missing
+    /// values as `0`.
+    ///
+    /// The values that are stored for each variant are:
+    ///
+    /// | Variant                                 | First value         | Second value         |
+    /// | --------------------------------------- | ------------------- | -------------------- |
+    /// | `UiKit`                                 | `ui_view`           | `ui_view_controller` |
+    /// | `AppKit`                                | `ns_view`           | `ns_window`          |
+    /// | `Xlib`, `Xcb`                           | `window`            | `visual_id`          |
+    /// | `Drm`                                   | `plane`             | `connector_id`       |
+    /// | `Win32`                                 | `hwnd`              | `hinstance`          |
+    /// | `Haiku`                                 | `b_window`          | `b_direct_window`    |
+    /// | `Screen`                                | `window`            | `context`            |
+    /// | `WaylandSubsurface`                     | `surface`           | `parent`             |
+    /// | `WebCanvasSelector`                     | `selector`          | `len`                |
+    /// | `DmaBuf`                                | `fd`                | `format`             |
+    /// | `PlayStation`                           | `video_out_handle`  | `display_index`      |
+    /// | `Custom`                                | `handle`            | `vendor_id`          |
+    /// | `Tizen`                                 | `evas_object`       | `wl_surface`         |
+    /// | `Null`                                  | `0`                 | `0`                  |
+    ///
+    /// All other variants store their [primary pointer][Self::primary_pointer] (or the `id` of a
+    /// `Web` handle) as the first value, and `0` as the second value.
+    ///
+    /// All other fields are dropped, see [`from_raw_parts`][Self::from_raw_parts].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use raw_window_handle::{RawWindowHandle, RawWindowHandleKind, XlibWindowHandle};
+    /// let handle = RawWindowHandle::from(XlibWindowHandle::new(42).with_visual_id(7));
+    /// let (tag, window, visual_id) = handle.raw_parts();
+    /// assert_eq!(RawWindowHandleKind::ALL[tag as usize], RawWindowHandleKind::Xlib);
+    /// assert_eq!((window, visual_id), (42, 7));
+    ///
+    /// assert_eq!(RawWindowHandle::from_raw_parts(tag, window, visual_id), Some(handle));
+    /// ```
+    pub fn raw_parts(&self) -> (u8, usize, usize) {
+        fn addr<T>(ptr: NonNull<T>) -> usize {
+            ptr.as_ptr() as usize
+        }
+        fn opt_addr<T>(ptr: Option<NonNull<T>>) -> usize {
+            ptr.map_or(0, addr)
+        }
+
+        let (a, b) = match self {
+            Self::UiKit(h) => (addr(h.ui_view), opt_addr(h.ui_view_controller)),
+            Self::AppKit(h) => (addr(h.ns_view), opt_addr(h.ns_window)),
+            Self::Xlib(h) => (h.window as usize, h.visual_id as usize),
+            Self::Xcb(h) => (
+                h.window.get() as usize,
+                h.visual_id.map_or(0, |id| id.get() as usize),
+            ),
+            Self::Drm(h) => (h.plane as usize, h.connector_id as usize),
+            Self::Win32(h) => (
+                h.hwnd.get() as usize,
+                h.hinstance.map_or(0, |hinstance| hinstance.get() as usize),
+            ),
+            Self::Web(h) => (h.id as usize, 0),
+            Self::Haiku(h) => (addr(h.b_window), opt_addr(h.b_direct_window)),
+            Self::Screen(h) => (addr(h.window), addr(h.context)),
+            Self::WaylandSubsurface(h) => (addr(h.surface), addr(h.parent)),
+            Self::WebCanvasSelector(h) => (addr(h.selector), h.len),
+            Self::DmaBuf(h) => (h.fd as usize, h.format as usize),
+            Self::PlayStation(h) => (h.video_out_handle as usize, h.display_index as usize),
+            Self::Custom(h) => (addr(h.handle), h.vendor_id as usize),
+            Self::Tizen(h) => (addr(h.evas_object), opt_addr(h.wl_surface)),
+            _ => (opt_addr(self.primary_pointer()), 0),
+        };
+        (self.kind() as u8, a, b)
+    }
+
+    /// Reconstruct a handle from the parts returned by [`raw_parts`][Self::raw_parts].
+    ///
+    /// Fields that are not part of the flattened representation are set to their defaults, so
+    /// the handle may have less information than the original. `None` is returned if the tag is
+    /// unknown, if a value that must be non-zero is zero, or if the variant cannot be
+    /// reconstructed from two values, which is the case for `WaylandSubsurface` and `DmaBuf`.
+    ///
+    /// See [`raw_parts`][Self::raw_parts] for an example.
+    pub fn from_raw_parts(tag: u8, a: usize, b: usize) -> Option<Self> {
+        fn ptr<T>(addr: usize) -> Option<NonNull<T>> {
+            NonNull::new(addr as *mut T)
+        }
+
+        use RawWindowHandleKind as K;
+        Some(match *RawWindowHandleKind::ALL.get(tag as usize)? {
+            K::UiKit => UiKitWindowHandle::new(ptr(a)?)
+                .with_ui_view_controller(ptr(b))
+                .into(),
+            K::AppKit => AppKitWindowHandle::new(ptr(a)?)
+                .with_ns_window(ptr(b))
+                .into(),
+            K::Orbital => OrbitalWindowHandle::new(ptr(a)?).into(),
+            K::OhosNdk => OhosNdkWindowHandle::new(ptr(a)?).into(),
+            K::Xlib => XlibWindowHandle::new(a as _).with_visual_id(b as _).into(),
+            K::Xcb => XcbWindowHandle::new(core::num::NonZeroU32::new(a as u32)?)
+                .with_visual_id(core::num::NonZeroU32::new(b as u32))
+                .into(),
+            K::Wayland => WaylandWindowHandle::new(ptr(a)?).into(),
+            K::Drm => DrmWindowHandle::new(a as u32)
+                .with_connector(b as u32)
+                .into(),
+            K::Gbm => GbmWindowHandle::new(ptr(a)?).into(),
+            K::Win32 => Win32WindowHandle::new(core::num::NonZeroIsize::new(a as isize)?)
+                .with_hinstance(core::num::NonZeroIsize::new(b as isize))
+                .into(),
+            K::WinRt => WinRtWindowHandle::new(ptr(a)?).into(),
+            K::Web => WebWindowHandle::new(a as u32).into(),
+            K::WebCanvas => WebCanvasWindowHandle::new(ptr(a)?).into(),
+            K::WebOffscreenCanvas => WebOffscreenCanvasWindowHandle::new(ptr(a)?).into(),
+            K::AndroidNdk => AndroidNdkWindowHandle::new(ptr(a)?).into(),
+            K::Haiku => HaikuWindowHandle::new(ptr(a)?)
+                .with_b_direct_window(ptr(b))
+                .into(),
+            K::Screen => ScreenWindowHandle::new(ptr(a)?, ptr(b)?).into(),
+            K::Switch => SwitchWindowHandle::new(ptr(a)?).into(),
+            K::AppKitMetalLayer => AppKitMetalLayerWindowHandle::new(ptr(a)?).into(),
+            K::UiKitMetalLayer => UiKitMetalLayerWindowHandle::new(ptr(a)?).into(),
+            K::WebCanvasSelector => WebCanvasSelectorWindowHandle::new(ptr(a)?, b).into(),
+            K::Fuchsia => FuchsiaViewWindowHandle::new(ptr(a)?).into(),
+            K::WinUi => WinUiSwapChainPanelWindowHandle::new(ptr(a)?).into(),
+            K::PlayStation => PlayStationWindowHandle::new(a as c_int)
+                .with_display_index(b as u32)
+                .into(),
+            K::Custom => CustomWindowHandle::new(b as u32, ptr(a)?).into(),
+            K::Tizen => TizenWindowHandle::new(ptr(a)?)
+                .with_wl_surface(ptr(b))
+                .into(),
+            K::Sdl => SdlMetalWindowHandle::new(ptr(a)?).into(),
+            K::Null => NullWindowHandle::new().into(),
+            K::WaylandSubsurface | K::DmaBuf => return None,
+        })
+    }
+
+    /// The primary pointer or ID of the handle.
+    // `c_ulong` is not `u64` on all platforms.
+    #[allow(clippy::useless_conversion)]
+    fn primary_value(&self) -> u64 {
+        match self {
+            Self::Xlib(h) => u64::from(h.window),
+            Self::Xcb(h) => u64::from(h.window.get()),
+            Self::Drm(h) => u64::from(h.plane),
+            Self::Win32(h) => h.hwnd.get() as u64,
+            Self::Web(h) => u64::from(h.id),
+            Self::DmaBuf(h) => h.fd as u32 as u64,
+            Self::PlayStation(h) => h.video_out_handle as u32 as u64,
+            _ => self
+                .primary_pointer()
+                .map_or(0, |ptr| ptr.as_ptr() as usize as u64),
+        }
+    }
+}
+
+/// A compact, one-line summary of the handle, intended for logging.
+///
+/// Pointers are formatted in hexadecimal, and IDs in decimal. Use the [`Debug`][fmt::Debug]
+/// implementation to see all fields.
+///
+/// # Example
+///
+/// ```
+/// # use core::num::NonZeroIsize;
+/// # use raw_window_handle::{RawWindowHandle, Win32WindowHandle};
+/// let handle = RawWindowHandle::from(Win32WindowHandle::new(NonZeroIsize::new(0x1234).unwrap()));
+/// assert_eq!(handle.to_string(), "Win32(hwnd=0x1234)");
+/// ```
+impl fmt::Display for RawWindowHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UiKit(h) => write!(f, "UiKit(ui_view={:p})", h.ui_view),
+            Self::AppKit(h) => write!(f, "AppKit(ns_view={:p})", h.ns_view),
+            Self::Orbital(h) => write!(f, "Orbital(window={:p})", h.window),
+            Self::OhosNdk(h) => write!(f, "OhosNdk(native_window={:p})", h.native_window),
+            Self::Xlib(h) => write!(f, "Xlib(window={})", h.window),
+            Self::Xcb(h) => write!(f, "Xcb(window={})", h.window),
+            Self::Wayland(h) => write!(f, "Wayland(surface={:p})", h.surface),
+            Self::Drm(h) => write!(f, "Drm(plane={})", h.plane),
+            Self::Gbm(h) => write!(f, "Gbm(gbm_surface={:p})", h.gbm_surface),
+            Self::Win32(h) => write!(f, "Win32(hwnd={:#x})", h.hwnd),
+            Self::WinRt(h) => write!(f, "WinRt(core_window={:p})", h.core_window),
+            Self::Web(h) => write!(f, "Web(id={})", h.id),
+            Self::WebCanvas(h) => write!(f, "WebCanvas(obj={:p})", h.obj),
+            Self::WebOffscreenCanvas(h) => write!(f, "WebOffscreenCanvas(obj={:p})", h.obj),
+            Self::AndroidNdk(h) => write!(f, "AndroidNdk(a_native_window={:p})", h.a_native_window),
+            Self::Haiku(h) => write!(f, "Haiku(b_window={:p})", h.b_window),
+            Self::Screen(h) => write!(f, "Screen(window={:p})", h.window),
+            Self::WaylandSubsurface(h) => write!(
+                f,
+                "WaylandSubsurface(surface={:p}, parent={:p})",
+                h.surface, h.parent
+            ),
+            Self::Switch(h) => write!(f, "Switch(nwindow={:p})", h.nwindow),
+            Self::AppKitMetalLayer(h) => write!(f, "AppKitMetalLayer(layer={:p})", h.layer),
+            Self::UiKitMetalLayer(h) => write!(f, "UiKitMetalLayer(layer={:p})", h.layer),
+            Self::WebCanvasSelector(h) => write!(
+                f,
+                "WebCanvasSelector(selector={:p}, len={})",
+                h.selector, h.len
+            ),
+            Self::Fuchsia(h) => write!(f, "Fuchsia(view_ref={:p})", h.view_ref),
+            Self::WinUi(h) => write!(f, "WinUi(panel={:p})", h.panel),
+            Self::DmaBuf(h) => write!(f, "DmaBuf(fd={})", h.fd),
+            Self::PlayStation(h) => {
+                write!(f, "PlayStation(video_out_handle={})", h.video_out_handle)
+            }
+            Self::Custom(h) => write!(
+                f,
+                "Custom(vendor_id={:#x}, handle={:p})",
+                h.vendor_id, h.handle
+            ),
+            Self::Tizen(h) => write!(f, "Tizen(evas_object={:p})", h.evas_object),
+            Self::Sdl(h) => write!(f, "Sdl(metal_view={:p})", h.metal_view),
+            Self::Null(_) => f.write_str("Null"),
+        }
+    }
+}
+
+/// The kind of a [`RawWindowHandle`], without the platform-specific payload.
+///
+/// This can be useful for logging, or for building lookup tables keyed on the windowing backend.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RawWindowHandleKind {
+    /// See [`RawWindowHandle::UiKit`].
+    UiKit,
+    /// See [`RawWindowHandle::AppKit`].
+    AppKit,
+    /// See [`RawWindowHandle::Orbital`].
+    Orbital,
+    /// See [`RawWindowHandle::OhosNdk`].
+    OhosNdk,
+    /// See [`RawWindowHandle::Xlib`].
+    Xlib,
+    /// See [`RawWindowHandle::Xcb`].
+    Xcb,
+    /// See [`RawWindowHandle::Wayland`].
+    Wayland,
+    /// See [`RawWindowHandle::Drm`].
+    Drm,
+    /// See [`RawWindowHandle::Gbm`].
+    Gbm,
+    /// See [`RawWindowHandle::Win32`].
+    Win32,
+    /// See [`RawWindowHandle::WinRt`].
+    WinRt,
+    /// See [`RawWindowHandle::Web`].
+    Web,
+    /// See [`RawWindowHandle::WebCanvas`].
+    WebCanvas,
+    /// See [`RawWindowHandle::WebOffscreenCanvas`].
+    WebOffscreenCanvas,
+    /// See [`RawWindowHandle::AndroidNdk`].
+    AndroidNdk,
+    /// See [`RawWindowHandle::Haiku`].
+    Haiku,
+    /// See [`RawWindowHandle::Screen`].
+    Screen,
+    /// See [`RawWindowHandle::WaylandSubsurface`].
+    WaylandSubsurface,
+    /// See [`RawWindowHandle::Switch`].
+    Switch,
+    /// See [`RawWindowHandle::AppKitMetalLayer`].
+    AppKitMetalLayer,
+    /// See [`RawWindowHandle::UiKitMetalLayer`].
+    UiKitMetalLayer,
+    /// See [`RawWindowHandle::WebCanvasSelector`].
+    WebCanvasSelector,
+    /// See [`RawWindowHandle::Fuchsia`].
+    Fuchsia,
+    /// See [`RawWindowHandle::WinUi`].
+    WinUi,
+    /// See [`RawWindowHandle::DmaBuf`].
+    DmaBuf,
+    /// See [`RawWindowHandle::Null`].
+    Null,
+    /// See [`RawWindowHandle::PlayStation`].
+    PlayStation,
+    /// See [`RawWindowHandle::Custom`].
+    Custom,
+    /// See [`RawWindowHandle::Tizen`].
+    Tizen,
+    /// See [`RawWindowHandle::Sdl`].
+    Sdl,
+}
+
+impl RawWindowHandleKind {
+    /// Every kind of [`RawWindowHandle`].
+    pub const ALL: &'static [Self] = &[
+        Self::UiKit,
+        Self::AppKit,
+        Self::Orbital,
+        Self::OhosNdk,
+        Self::Xlib,
+        Self::Xcb,
+        Self::Wayland,
+        Self::Drm,
+        Self::Gbm,
+        Self::Win32,
+        Self::WinRt,
+        Self::Web,
+        Self::WebCanvas,
+        Self::WebOffscreenCanvas,
+        Self::AndroidNdk,
+        Self::Haiku,
+        Self::Screen,
+        Self::WaylandSubsurface,
+        Self::Switch,
+        Self::AppKitMetalLayer,
+        Self::UiKitMetalLayer,
+        Self::WebCanvasSelector,
+        Self::Fuchsia,
+        Self::WinUi,
+        Self::DmaBuf,
+        Self::Null,
+        Self::PlayStation,
+        Self::Custom,
+        Self::Tizen,
+        Self::Sdl,
+    ];
+
+    /// Iterate over every kind of [`RawWindowHandle`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use raw_window_handle::RawWindowHandleKind;
+    /// assert!(RawWindowHandleKind::all().any(|kind| kind == RawWindowHandleKind::Xlib));
+    /// ```
+    pub fn all() -> impl Iterator<Item = Self> {
+        Self::ALL.iter().copied()
+    }
 }
 
 /// Display that wraps around a raw display handle.
@@ -262,7 +1075,8 @@ unsafe impl<T: HasDisplayHandle + ?Sized> HasRawDisplayHandle for T {
 /// [`RawDisplayHandle::Xlib`] on macOS, it would just be weird, and probably
 /// requires something like XQuartz be used).
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RawDisplayHandle {
     /// A raw display handle for UIKit (Apple's non-macOS windowing library).
     ///
@@ -341,6 +1155,281 @@ pub enum RawDisplayHandle {
     /// ## Availability Hints
     /// This variant is used on HaikuOS.
     Haiku(HaikuDisplayHandle),
+    /// A raw display handle for the QNX Screen Graphics Subsystem.
+    ///
+    /// ## Availability Hints
+    /// This variant is used on QNX Neutrino (`target_os = "nto"`).
+    Screen(ScreenDisplayHandle),
+    /// A raw display handle for Vulkan's `VK_KHR_display` extension.
+    ///
+    /// ## Availability Hints
+    /// This variant is used for direct-to-display rendering without a windowing
+    /// system, typically on embedded or headless systems.
+    Vulkan(VulkanDisplayHandle),
+    /// A raw display handle for the Nintendo Switch.
+    ///
+    /// ## Availability Hints
+    /// This variant is used on the Nintendo Switch (`target_os = "horizon"`).
+    Switch(SwitchDisplayHandle),
+    /// A raw display handle for a Web Worker.
+    ///
+    /// ## Availability Hints
+    /// This variant is used on Wasm or asm.js targets when rendering to an `OffscreenCanvas` from a
+    /// Web Worker.
+    WebWorker(WebWorkerDisplayHandle),
+    /// A raw display handle for headless rendering.
+    ///
+    /// ## Availability Hints
+    /// This variant may be used on any platform when rendering without a display server, such as for
+    /// compute or offscreen rendering.
+    Headless(HeadlessDisplayHandle),
+}
+
+impl RawDisplayHandle {
+    /// The size in bytes of the largest display handle, including the discriminant.
+    ///
+    /// This is the same as `size_of::<RawDisplayHandle>()`, and may be used to size buffers that
+    /// handles are stored in. It is currently 32 bytes on 64-bit platforms, but may grow when new
+    /// variants or fields are added.
+    pub const fn max_size() -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    /// Get the kind of display handle, without its payload.
+    pub fn kind(&self) -> RawDisplayHandleKind {
+        match self {
+            Self::UiKit(_) => RawDisplayHandleKind::UiKit,
+            Self::AppKit(_) => RawDisplayHandleKind::AppKit,
+            Self::Orbital(_) => RawDisplayHandleKind::Orbital,
+            Self::Ohos(_) => RawDisplayHandleKind::Ohos,
+            Self::Xlib(_) => RawDisplayHandleKind::Xlib,
+            Self::Xcb(_) => RawDisplayHandleKind::Xcb,
+            Self::Wayland(_) => RawDisplayHandleKind::Wayland,
+            Self::Drm(_) => RawDisplayHandleKind::Drm,
+            Self::Gbm(_) => RawDisplayHandleKind::Gbm,
+            Self::Windows(_) => RawDisplayHandleKind::Windows,
+            Self::Web(_) => RawDisplayHandleKind::Web,
+            Self::Android(_) => RawDisplayHandleKind::Android,
+            Self::Haiku(_) => RawDisplayHandleKind::Haiku,
+            Self::Screen(_) => RawDisplayHandleKind::Screen,
+            Self::Vulkan(_) => RawDisplayHandleKind::Vulkan,
+            Self::Switch(_) => RawDisplayHandleKind::Switch,
+            Self::WebWorker(_) => RawDisplayHandleKind::WebWorker,
+            Self::Headless(_) => RawDisplayHandleKind::Headless,
+        }
+    }
+
+    /// Get the file descriptor of the display connection, if the handle provides one.
+    ///
+    /// This is the descriptor that can be registered with `poll` or `epoll` to integrate the
+    /// display into a custom event loop. It is available for [`Xcb`][Self::Xcb] (if
+    /// [`XcbDisplayHandle::with_fd`] was used) and [`Drm`][Self::Drm], and `None` is returned for all
+    /// other handles, including Wayland and Xlib, where the descriptor has to be queried from
+    /// the connection itself.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use raw_window_handle::{DrmDisplayHandle, RawDisplayHandle, WindowsDisplayHandle};
+    /// let display = RawDisplayHandle::from(DrmDisplayHandle::new(3));
+    /// assert_eq!(display.connection_fd(), Some(3));
+    ///
+    /// let display = RawDisplayHandle::from(WindowsDisplayHandle::new());
+    /// assert_eq!(display.connection_fd(), None);
+    /// ```
+    pub fn connection_fd(&self) -> Option<c_int> {
+        match self {
+            Self::Xcb(handle) => handle.fd(),
+            Self::Drm(handle) => (handle.fd >= 0).then_some(handle.fd),
+            _ => None,
+        }
+    }
+
+    /// Whether this display handle contains any pointer fields.
+    ///
+    /// Handles that only contain integers (such as the file descriptor of a [`DrmDisplayHandle`])
+    /// may be easier to reason about when sending them to other threads, see
+    /// [`SendSyncRawDisplayHandle`].
+    pub fn is_pointer_based(&self) -> bool {
+        match self {
+            Self::Xlib(_)
+            | Self::Xcb(_)
+            | Self::Wayland(_)
+            | Self::Gbm(_)
+            | Self::Screen(_)
+            | Self::Vulkan(_) => true,
+            Self::UiKit(_)
+            | Self::AppKit(_)
+            | Self::Orbital(_)
+            | Self::Ohos(_)
+            | Self::Drm(_)
+            | Self::Windows(_)
+            | Self::Web(_)
+            | Self::Android(_)
+            | Self::Haiku(_)
+            | Self::Switch(_)
+            | Self::WebWorker(_)
+            | Self::Headless(_) => false,
+        }
+    }
+}
+
+/// The kind of a [`RawDisplayHandle`], without the platform-specific payload.
+///
+/// This can be useful for logging, or for building lookup tables keyed on the windowing backend.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RawDisplayHandleKind {
+    /// See [`RawDisplayHandle::UiKit`].
+    UiKit,
+    /// See [`RawDisplayHandle::AppKit`].
+    AppKit,
+    /// See [`RawDisplayHandle::Orbital`].
+    Orbital,
+    /// See [`RawDisplayHandle::Ohos`].
+    Ohos,
+    /// See [`RawDisplayHandle::Xlib`].
+    Xlib,
+    /// See [`RawDisplayHandle::Xcb`].
+    Xcb,
+    /// See [`RawDisplayHandle::Wayland`].
+    Wayland,
+    /// See [`RawDisplayHandle::Drm`].
+    Drm,
+    /// See [`RawDisplayHandle::Gbm`].
+    Gbm,
+    /// See [`RawDisplayHandle::Windows`].
+    Windows,
+    /// See [`RawDisplayHandle::Web`].
+    Web,
+    /// See [`RawDisplayHandle::Android`].
+    Android,
+    /// See [`RawDisplayHandle::Haiku`].
+    Haiku,
+    /// See [`RawDisplayHandle::Screen`].
+    Screen,
+    /// See [`RawDisplayHandle::Vulkan`].
+    Vulkan,
+    /// See [`RawDisplayHandle::Switch`].
+    Switch,
+    /// See [`RawDisplayHandle::WebWorker`].
+    WebWorker,
+    /// See [`RawDisplayHandle::Headless`].
+    Headless,
+}
+
+impl RawDisplayHandleKind {
+    /// Every kind of [`RawDisplayHandle`].
+    pub const ALL: &'static [Self] = &[
+        Self::UiKit,
+        Self::AppKit,
+        Self::Orbital,
+        Self::Ohos,
+        Self::Xlib,
+        Self::Xcb,
+        Self::Wayland,
+        Self::Drm,
+        Self::Gbm,
+        Self::Windows,
+        Self::Web,
+        Self::Android,
+        Self::Haiku,
+        Self::Screen,
+        Self::Vulkan,
+        Self::Switch,
+        Self::WebWorker,
+        Self::Headless,
+    ];
+
+    /// Iterate over every kind of [`RawDisplayHandle`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use raw_window_handle::RawDisplayHandleKind;
+    /// assert!(RawDisplayHandleKind::all().any(|kind| kind == RawDisplayHandleKind::Xlib));
+    /// ```
+    pub fn all() -> impl Iterator<Item = Self> {
+        Self::ALL.iter().copied()
+    }
+}
+
+/// Check whether a window handle and a display handle belong to compatible backends.
+///
+/// This returns `false` for combinations that are obviously incompatible, such as a
+/// [`RawWindowHandle::Wayland`] window with a [`RawDisplayHandle::Xlib`] display, and can be used
+/// to catch mismatched handles before passing them on to a graphics driver. Note that returning
+/// `true` does not guarantee that the window actually belongs to the display.
+///
+/// The compatible combinations are:
+///
+/// | Window handle                                                   | Display handle         |
+/// | --------------------------------------------------------------- | ---------------------- |
+/// | `UiKit`, `UiKitMetalLayer`, `Sdl`                               | `UiKit`                |
+/// | `AppKit`, `AppKitMetalLayer`, `Sdl`                             | `AppKit`               |
+/// | `Orbital`                                                       | `Orbital`              |
+/// | `OhosNdk`                                                       | `Ohos`                 |
+/// | `Xlib`, `Xcb`                                                   | `Xlib`, `Xcb`          |
+/// | `Wayland`, `WaylandSubsurface`, `Tizen`                         | `Wayland`              |
+/// | `Drm`, `Gbm`, `DmaBuf`                                          | `Drm`, `Gbm`           |
+/// | `Win32`, `WinRt`, `WinUi`                                       | `Windows`              |
+/// | `Web`, `WebCanvas`, `WebOffscreenCanvas`, `WebCanvasSelector`   | `Web`                  |
+/// | `WebOffscreenCanvas`                                            | `WebWorker`            |
+/// | `AndroidNdk`                                                    | `Android`              |
+/// | `Haiku`                                                         | `Haiku`                |
+/// | `Screen`                                                        | `Screen`               |
+/// | `Switch`                                                        | `Switch`               |
+///
+/// X11 windows are compatible with both Xlib and XCB displays, since they share the same XIDs.
+/// Likewise, DRM and GBM handles may be mixed, since a GBM device is created from a DRM file
+/// descriptor.
+///
+/// All other combinations, including every combination with a [`RawWindowHandle::Fuchsia`],
+/// [`RawWindowHandle::PlayStation`], [`RawWindowHandle::Custom`] or [`RawWindowHandle::Null`]
+/// window or a
+/// [`RawDisplayHandle::Vulkan`] or [`RawDisplayHandle::Headless`] display, are considered
+/// incompatible.
+///
+/// # Example
+///
+/// ```
+/// # use core::ptr::NonNull;
+/// # use raw_window_handle::{backends_match, WaylandWindowHandle, XlibDisplayHandle};
+/// let window = WaylandWindowHandle::new(NonNull::dangling()).into();
+/// let display = XlibDisplayHandle::new(None, 0).into();
+/// assert!(!backends_match(&window, &display));
+/// ```
+pub fn backends_match(window: &RawWindowHandle, display: &RawDisplayHandle) -> bool {
+    use RawDisplayHandle as D;
+    use RawWindowHandle as W;
+
+    matches!(
+        (window, display),
+        (W::UiKit(_) | W::UiKitMetalLayer(_) | W::Sdl(_), D::UiKit(_))
+            | (
+                W::AppKit(_) | W::AppKitMetalLayer(_) | W::Sdl(_),
+                D::AppKit(_)
+            )
+            | (W::Orbital(_), D::Orbital(_))
+            | (W::OhosNdk(_), D::Ohos(_))
+            | (W::Xlib(_) | W::Xcb(_), D::Xlib(_) | D::Xcb(_))
+            | (
+                W::Wayland(_) | W::WaylandSubsurface(_) | W::Tizen(_),
+                D::Wayland(_)
+            )
+            | (W::Drm(_) | W::Gbm(_) | W::DmaBuf(_), D::Drm(_) | D::Gbm(_))
+            | (W::Win32(_) | W::WinRt(_) | W::WinUi(_), D::Windows(_))
+            | (
+                W::Web(_) | W::WebCanvas(_) | W::WebOffscreenCanvas(_) | W::WebCanvasSelector(_),
+                D::Web(_)
+            )
+            | (W::WebOffscreenCanvas(_), D::WebWorker(_))
+            | (W::AndroidNdk(_), D::Android(_))
+            | (W::Haiku(_), D::Haiku(_))
+            | (W::Screen(_), D::Screen(_))
+            | (W::Switch(_), D::Switch(_))
+    )
 }
 
 /// An error that can occur while fetching a display or window handle.
@@ -358,7 +1447,8 @@ pub enum HandleError {
     /// like some game consoles.
     ///
     /// In the event that this error is returned, you should try to use the underlying window
-    /// system's native API to get the handle you need.
+    /// system's native API to get the handle you need. Unlike [`HandleError::Unavailable`],
+    /// retrying will not help, so this is a good point to fall back to a different backend.
     ///
     /// [`x11rb`]: https://crates.io/crates/x11rb
     NotSupported,
@@ -371,7 +1461,8 @@ pub enum HandleError {
     /// similar situation can occur on Wayland for the layer shell windows.
     ///
     /// In the event that this error is returned, you should wait until the handle becomes available
-    /// again.
+    /// again. This is distinct from [`HandleError::NotSupported`], where the handle will never
+    /// become available.
     Unavailable,
 }
 
@@ -388,8 +1479,24 @@ impl fmt::Display for HandleError {
 }
 
 #[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl std::error::Error for HandleError {}
 
+// Guard against accidentally growing the handles, since some users store them in fixed-size buffers.
+// The 32-bit sizes depend on the alignment of `u64`.
+#[cfg(target_pointer_width = "64")]
+const _: () = {
+    assert!(RawWindowHandle::max_size() == 48);
+    assert!(core::mem::align_of::<RawWindowHandle>() == 8);
+    assert!(RawDisplayHandle::max_size() == 32);
+    assert!(core::mem::align_of::<RawDisplayHandle>() == 8);
+};
+#[cfg(target_pointer_width = "32")]
+const _: () = {
+    assert!(RawWindowHandle::max_size() <= 32);
+    assert!(RawDisplayHandle::max_size() <= 24);
+};
+
 macro_rules! from_impl {
     ($($to:ident, $enum:ident, $from:ty)*) => ($(
         impl From<$from> for $to {
@@ -397,6 +1504,17 @@ macro_rules! from_impl {
                 $to::$enum(value)
             }
         }
+
+        impl TryFrom<$to> for $from {
+            type Error = $to;
+
+            fn try_from(value: $to) -> Result<Self, Self::Error> {
+                match value {
+                    $to::$enum(handle) => Ok(handle),
+                    value => Err(value),
+                }
+            }
+        }
     )*)
 }
 
@@ -413,6 +1531,11 @@ from_impl!(RawDisplayHandle, Windows, WindowsDisplayHandle);
 from_impl!(RawDisplayHandle, Web, WebDisplayHandle);
 from_impl!(RawDisplayHandle, Android, AndroidDisplayHandle);
 from_impl!(RawDisplayHandle, Haiku, HaikuDisplayHandle);
+from_impl!(RawDisplayHandle, Screen, ScreenDisplayHandle);
+from_impl!(RawDisplayHandle, Vulkan, VulkanDisplayHandle);
+from_impl!(RawDisplayHandle, Switch, SwitchDisplayHandle);
+from_impl!(RawDisplayHandle, WebWorker, WebWorkerDisplayHandle);
+from_impl!(RawDisplayHandle, Headless, HeadlessDisplayHandle);
 
 from_impl!(RawWindowHandle, UiKit, UiKitWindowHandle);
 from_impl!(RawWindowHandle, AppKit, AppKitWindowHandle);
@@ -434,10 +1557,41 @@ from_impl!(
 );
 from_impl!(RawWindowHandle, AndroidNdk, AndroidNdkWindowHandle);
 from_impl!(RawWindowHandle, Haiku, HaikuWindowHandle);
+from_impl!(RawWindowHandle, Screen, ScreenWindowHandle);
+from_impl!(
+    RawWindowHandle,
+    WaylandSubsurface,
+    WaylandSubsurfaceWindowHandle
+);
+from_impl!(RawWindowHandle, Switch, SwitchWindowHandle);
+from_impl!(
+    RawWindowHandle,
+    AppKitMetalLayer,
+    AppKitMetalLayerWindowHandle
+);
+from_impl!(
+    RawWindowHandle,
+    UiKitMetalLayer,
+    UiKitMetalLayerWindowHandle
+);
+from_impl!(
+    RawWindowHandle,
+    WebCanvasSelector,
+    WebCanvasSelectorWindowHandle
+);
+from_impl!(RawWindowHandle, Fuchsia, FuchsiaViewWindowHandle);
+from_impl!(RawWindowHandle, WinUi, WinUiSwapChainPanelWindowHandle);
+from_impl!(RawWindowHandle, DmaBuf, DmaBufWindowHandle);
+from_impl!(RawWindowHandle, Null, NullWindowHandle);
+from_impl!(RawWindowHandle, PlayStation, PlayStationWindowHandle);
+from_impl!(RawWindowHandle, Custom, CustomWindowHandle);
+from_impl!(RawWindowHandle, Tizen, TizenWindowHandle);
+from_impl!(RawWindowHandle, Sdl, SdlMetalWindowHandle);
 
 #[cfg(test)]
 mod tests {
     use core::panic::{RefUnwindSafe, UnwindSafe};
+    use core::ptr::NonNull;
     use static_assertions::{assert_impl_all, assert_not_impl_any};
 
     use super::*;
@@ -446,13 +1600,17 @@ mod tests {
     fn auto_traits() {
         assert_impl_all!(RawDisplayHandle: UnwindSafe, RefUnwindSafe, Unpin);
         assert_not_impl_any!(RawDisplayHandle: Send, Sync);
-        assert_impl_all!(DisplayHandle<'_>: UnwindSafe, RefUnwindSafe, Unpin);
+        assert_impl_all!(DisplayHandle<'_>: Copy, UnwindSafe, RefUnwindSafe, Unpin);
         assert_not_impl_any!(DisplayHandle<'_>: Send, Sync);
         assert_impl_all!(RawWindowHandle: UnwindSafe, RefUnwindSafe, Unpin);
         assert_not_impl_any!(RawWindowHandle: Send, Sync);
-        assert_impl_all!(WindowHandle<'_>: UnwindSafe, RefUnwindSafe, Unpin);
+        assert_impl_all!(WindowHandle<'_>: Copy, UnwindSafe, RefUnwindSafe, Unpin);
         assert_not_impl_any!(WindowHandle<'_>: Send, Sync);
+        assert_impl_all!(SendSyncRawDisplayHandle: Send, Sync);
+        assert_impl_all!(SendSyncRawWindowHandle: Send, Sync);
         assert_impl_all!(HandleError: Send, Sync, UnwindSafe, RefUnwindSafe, Unpin);
+        assert_impl_all!(RawDisplayHandleKind: Send, Sync, UnwindSafe, RefUnwindSafe, Unpin);
+        assert_impl_all!(RawWindowHandleKind: Send, Sync, UnwindSafe, RefUnwindSafe, Unpin);
 
         // TODO: Unsure if some of these should not actually be Send + Sync
         assert_impl_all!(UiKitDisplayHandle: Send, Sync);
@@ -468,6 +1626,11 @@ mod tests {
         assert_impl_all!(WebDisplayHandle: Send, Sync);
         assert_impl_all!(AndroidDisplayHandle: Send, Sync);
         assert_impl_all!(HaikuDisplayHandle: Send, Sync);
+        assert_not_impl_any!(ScreenDisplayHandle: Send, Sync);
+        assert_not_impl_any!(VulkanDisplayHandle: Send, Sync);
+        assert_impl_all!(SwitchDisplayHandle: Send, Sync);
+        assert_impl_all!(WebWorkerDisplayHandle: Send, Sync);
+        assert_impl_all!(HeadlessDisplayHandle: Send, Sync);
 
         // TODO: Unsure if some of these should not actually be Send + Sync
         assert_not_impl_any!(UiKitWindowHandle: Send, Sync);
@@ -486,6 +1649,513 @@ mod tests {
         assert_not_impl_any!(WebOffscreenCanvasWindowHandle: Send, Sync);
         assert_not_impl_any!(AndroidNdkWindowHandle: Send, Sync);
         assert_not_impl_any!(HaikuWindowHandle: Send, Sync);
+        assert_not_impl_any!(ScreenWindowHandle: Send, Sync);
+        assert_not_impl_any!(WaylandSubsurfaceWindowHandle: Send, Sync);
+        assert_not_impl_any!(SwitchWindowHandle: Send, Sync);
+        assert_not_impl_any!(AppKitMetalLayerWindowHandle: Send, Sync);
+        assert_not_impl_any!(UiKitMetalLayerWindowHandle: Send, Sync);
+        assert_not_impl_any!(WebCanvasSelectorWindowHandle: Send, Sync);
+        assert_not_impl_any!(FuchsiaViewWindowHandle: Send, Sync);
+        assert_not_impl_any!(WinUiSwapChainPanelWindowHandle: Send, Sync);
+        assert_impl_all!(DmaBufWindowHandle: Send, Sync);
+        assert_impl_all!(NullWindowHandle: Send, Sync);
+        assert_impl_all!(PlayStationWindowHandle: Send, Sync);
+        assert_not_impl_any!(CustomWindowHandle: Send, Sync);
+        assert_not_impl_any!(TizenWindowHandle: Send, Sync);
+        assert_not_impl_any!(SdlMetalWindowHandle: Send, Sync);
+        #[cfg(feature = "alloc")]
+        assert_impl_all!(OwnedDisplayHandle: Send, Sync, Clone);
+    }
+
+    #[test]
+    fn handle_error_display_without_alloc() {
+        use core::fmt::Write;
+
+        struct Buf([u8; 128], usize);
+
+        impl Write for Buf {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                let end = self.1 + s.len();
+                self.0
+                    .get_mut(self.1..end)
+                    .ok_or(fmt::Error)?
+                    .copy_from_slice(s.as_bytes());
+                self.1 = end;
+                Ok(())
+            }
+        }
+
+        let mut buf = Buf([0; 128], 0);
+        write!(buf, "{}", HandleError::Unavailable).unwrap();
+        assert_eq!(&buf.0[..buf.1], b"the underlying handle is not available");
+    }
+
+    #[test]
+    fn try_from_raw() {
+        let raw = RawWindowHandle::from(WebWindowHandle::new(3));
+        assert_eq!(WebWindowHandle::try_from(raw), Ok(WebWindowHandle::new(3)));
+        assert_eq!(Win32WindowHandle::try_from(raw), Err(raw));
+
+        let raw = RawDisplayHandle::from(DrmDisplayHandle::new(4));
+        assert_eq!(
+            DrmDisplayHandle::try_from(raw),
+            Ok(DrmDisplayHandle::new(4))
+        );
+        assert_eq!(WindowsDisplayHandle::try_from(raw), Err(raw));
+    }
+
+    #[test]
+    fn ordering() {
+        let a = RawWindowHandle::from(WebWindowHandle::new(1));
+        let b = RawWindowHandle::from(WebWindowHandle::new(2));
+        assert!(a < b);
+        assert_eq!(a.cmp(&a), core::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn debug_pointers_as_hex() {
+        use alloc::format;
+
+        let raw = RawWindowHandle::from(XlibWindowHandle::new(42).with_visual_id(7));
+        assert_eq!(
+            format!("{:?}", raw),
+            "Xlib(XlibWindowHandle { window: 42, visual_id: 7, screen: 0, depth: 0 })"
+        );
+
+        let raw = RawDisplayHandle::from(
+            WaylandDisplayHandle::new(NonNull::new(0x1000 as *mut _).unwrap())
+                .with_registry(NonNull::new(0x2000 as *mut _)),
+        );
+        assert_eq!(
+            format!("{:?}", raw),
+            "Wayland(WaylandDisplayHandle { display: 0x1000, registry: Some(0x2000), event_queue: None })"
+        );
+
+        let raw = RawWindowHandle::from(
+            Win32WindowHandle::new(core::num::NonZeroIsize::new(0x1234).unwrap())
+                .with_hinstance(core::num::NonZeroIsize::new(0x40_0000)),
+        );
+        assert_eq!(
+            format!("{:?}", raw),
+            "Win32(Win32WindowHandle { hwnd: 0x1234, hinstance: Some(0x400000), hdc: None, parent_hwnd: None })"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn parse_kinds() {
+        use alloc::{format, string::ToString};
+
+        for kind in RawWindowHandleKind::all() {
+            let name = format!("{:?}", kind).to_ascii_lowercase();
+            assert_eq!(name.parse(), Ok(kind));
+        }
+        for kind in RawDisplayHandleKind::all() {
+            let name = format!("{:?}", kind).to_ascii_uppercase();
+            assert_eq!(name.parse(), Ok(kind));
+        }
+        assert!("".parse::<RawWindowHandleKind>().is_err());
+        assert!("Xlibx".parse::<RawWindowHandleKind>().is_err());
+        assert!("Xli".parse::<RawDisplayHandleKind>().is_err());
+
+        let err = "x12".parse::<RawDisplayHandleKind>().unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("unknown display handle kind, expected one of: UiKit, AppKit,"));
+    }
+
+    #[test]
+    fn hash_identity() {
+        use core::hash::{BuildHasher, BuildHasherDefault};
+
+        #[derive(Default)]
+        struct XorHasher(u64);
+
+        impl Hasher for XorHasher {
+            fn finish(&self) -> u64 {
+                self.0
+            }
+
+            fn write(&mut self, bytes: &[u8]) {
+                for (i, byte) in bytes.iter().enumerate() {
+                    self.0 ^= u64::from(*byte).rotate_left(i as u32 * 8 + 1);
+                    self.0 = self.0.rotate_left(7);
+                }
+            }
+        }
+
+        let state = BuildHasherDefault::<XorHasher>::default();
+        let hash = |handle: RawWindowHandle| {
+            let mut hasher = state.build_hasher();
+            handle.hash_identity(&mut hasher);
+            hasher.finish()
+        };
+
+        let a = RawWindowHandle::from(XlibWindowHandle::new(1));
+        let b = RawWindowHandle::from(XlibWindowHandle::new(1).with_visual_id(2).with_screen(1));
+        let c = RawWindowHandle::from(XlibWindowHandle::new(2));
+        assert_ne!(a, b);
+        assert_eq!(hash(a), hash(b));
+        assert_ne!(hash(a), hash(c));
+        assert_eq!(a.identity(), b.identity());
+    }
+
+    #[test]
+    fn layout() {
+        use core::mem::align_of;
+
+        #[cfg(target_pointer_width = "64")]
+        {
+            assert_eq!(RawWindowHandle::max_size(), 48);
+            assert_eq!(RawDisplayHandle::max_size(), 32);
+        }
+        // 28 and 16 bytes on targets where `u64` is 4-byte aligned, such as i686.
+        #[cfg(target_pointer_width = "32")]
+        {
+            assert!(matches!(RawWindowHandle::max_size(), 28 | 32));
+            assert!(matches!(RawDisplayHandle::max_size(), 16 | 24));
+        }
+        assert_eq!(align_of::<RawWindowHandle>(), align_of::<u64>());
+        assert_eq!(align_of::<RawDisplayHandle>(), align_of::<u64>());
+    }
+
+    #[test]
+    fn pointer_based() {
+        assert!(!RawWindowHandle::from(WebWindowHandle::new(1)).is_pointer_based());
+        assert!(
+            RawWindowHandle::from(WaylandWindowHandle::new(NonNull::dangling())).is_pointer_based()
+        );
+        assert!(!RawDisplayHandle::from(WindowsDisplayHandle::new()).is_pointer_based());
+        assert!(RawDisplayHandle::from(XlibDisplayHandle::new(None, 0)).is_pointer_based());
+    }
+
+    #[test]
+    fn window_handle_accessors() {
+        let raw = RawWindowHandle::from(WebWindowHandle::new(5));
+        let handle = unsafe { WindowHandle::borrow_raw(raw) };
+        assert_eq!(handle.as_web(), Some(WebWindowHandle::new(5)));
+        assert_eq!(handle.as_win32(), None);
+        assert_eq!(handle, raw);
+        assert_eq!(raw, handle);
+
+        let display = DisplayHandle::web();
+        assert_eq!(display, RawDisplayHandle::from(WebDisplayHandle::new()));
+        assert_eq!(
+            display.get::<WebDisplayHandle>().ok(),
+            Some(WebDisplayHandle::new())
+        );
+        assert!(matches!(
+            display.get::<XlibDisplayHandle>(),
+            Err(HandleError::NotSupported)
+        ));
+
+        assert!(Some(handle).window_handle().is_ok());
+        assert!(core::pin::Pin::new(&handle).window_handle().is_ok());
+        assert!(matches!(
+            None::<WindowHandle<'_>>.window_handle(),
+            Err(HandleError::Unavailable)
+        ));
+        assert!(handle.is_window_available());
+        assert!(!None::<WindowHandle<'_>>.is_window_available());
+
+        assert_eq!(
+            window_handle_as::<WebWindowHandle, _>(&handle).ok(),
+            Some(WebWindowHandle::new(5))
+        );
+        assert!(matches!(
+            window_handle_as::<Win32WindowHandle, _>(&handle),
+            Err(HandleError::NotSupported)
+        ));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn raw_handle_blanket_impls() {
+        assert_impl_all!(&WindowHandle<'static>: HasRawWindowHandle);
+        assert_impl_all!(&DisplayHandle<'static>: HasRawDisplayHandle);
+
+        #[cfg(feature = "alloc")]
+        {
+            use alloc::{boxed::Box, rc::Rc, sync::Arc};
+
+            assert_impl_all!(Rc<WindowHandle<'static>>: HasRawWindowHandle);
+            assert_impl_all!(Arc<WindowHandle<'static>>: HasRawWindowHandle);
+            assert_impl_all!(Box<dyn HasWindowHandle>: HasRawWindowHandle);
+
+            let raw = RawWindowHandle::from(WebWindowHandle::new(5));
+            let window: Box<dyn HasRawWindowHandle> =
+                Box::new(unsafe { WindowHandle::borrow_raw(raw) });
+            assert_eq!(window.raw_window_handle().ok(), Some(raw));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn window_handle_set() {
+        let a = unsafe { WindowHandle::borrow_raw(XlibWindowHandle::new(1).into()) };
+        let b =
+            unsafe { WindowHandle::borrow_raw(XlibWindowHandle::new(1).with_visual_id(2).into()) };
+        let c = unsafe {
+            WindowHandle::borrow_raw(
+                XcbWindowHandle::new(core::num::NonZeroU32::new(1).unwrap()).into(),
+            )
+        };
+
+        let mut set = WindowHandleSet::new();
+        assert!(set.insert(&a).unwrap());
+        assert!(!set.insert(&b).unwrap());
+        assert!(set.insert(&c).unwrap());
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.iter().next(), Some(a.as_raw()));
+        assert!(matches!(
+            set.contains(&None::<WindowHandle<'_>>),
+            Err(HandleError::Unavailable)
+        ));
+    }
+
+    #[cfg(feature = "test-util")]
+    #[test]
+    fn dummy_handles() {
+        for kind in RawWindowHandleKind::all() {
+            let handle = RawWindowHandle::dummy(kind);
+            assert_eq!(handle.kind(), kind);
+            assert_eq!(
+                handle.primary_pointer().is_some(),
+                handle.is_pointer_based()
+            );
+            if let Some(display) = handle.default_display() {
+                assert!(backends_match(&handle, &display));
+            }
+
+            let (tag, a, b) = handle.raw_parts();
+            assert_eq!(RawWindowHandleKind::ALL[tag as usize], kind);
+            if let Some(rebuilt) = RawWindowHandle::from_raw_parts(tag, a, b) {
+                assert_eq!(rebuilt.raw_parts(), (tag, a, b));
+            }
+            assert_eq!(
+                RawWindowHandle::from_bytes(handle.to_bytes()).ok(),
+                Some(handle)
+            );
+        }
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary_handles() {
+        use ::arbitrary::{Arbitrary, Unstructured};
+
+        let mut data = [0u8; 1024];
+        for (i, byte) in data.iter_mut().enumerate() {
+            *byte = (i * 31 % 251) as u8;
+        }
+        let mut u = Unstructured::new(&data);
+        for _ in 0..16 {
+            let window = RawWindowHandle::arbitrary(&mut u).unwrap();
+            if let Some(ptr) = window.primary_pointer() {
+                assert_eq!(ptr, NonNull::dangling());
+            }
+            RawDisplayHandle::arbitrary(&mut u).unwrap();
+        }
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn bytemuck_layout() {
+        use core::mem::{align_of, size_of};
+
+        assert_eq!(size_of::<WindowsDisplayHandle>(), 0);
+        assert_eq!(size_of::<WebWindowHandle>(), 16);
+        assert_eq!(align_of::<WebWindowHandle>(), 8);
+        assert_eq!(size_of::<DrmDisplayHandle>(), 12);
+        assert_eq!(align_of::<DrmDisplayHandle>(), 4);
+        assert_eq!(size_of::<DrmWindowHandle>(), 12);
+        assert_eq!(align_of::<DrmWindowHandle>(), 4);
+        assert_eq!(size_of::<DmaBufWindowHandle>(), 24);
+        assert_eq!(align_of::<DmaBufWindowHandle>(), 8);
+
+        let handle = DrmWindowHandle::new(1).with_connector(2).with_crtc(3);
+        let bytes = bytemuck::bytes_of(&handle);
+        assert_eq!(
+            bytemuck::pod_read_unaligned::<DrmWindowHandle>(bytes),
+            handle
+        );
+
+        let handle: XlibDisplayHandle = bytemuck::Zeroable::zeroed();
+        assert_eq!(handle, XlibDisplayHandle::new(None, 0));
+
+        let handle: XcbDisplayHandle = bytemuck::Zeroable::zeroed();
+        assert_eq!(handle, XcbDisplayHandle::new(None, 0));
+        assert_eq!(RawDisplayHandle::from(handle).connection_fd(), None);
+        assert_eq!(
+            RawDisplayHandle::from(handle.with_fd(0)).connection_fd(),
+            Some(0)
+        );
+
+        let handle: DrmDisplayHandle = bytemuck::Zeroable::zeroed();
+        assert_eq!(handle, DrmDisplayHandle::new(0));
+        assert_eq!(handle.lease_fd(), None);
+
+        let handle = WebWindowHandle::new(1).with_scale_factor(2.0);
+        assert_eq!(
+            bytemuck::pod_read_unaligned::<WebWindowHandle>(bytemuck::bytes_of(&handle)),
+            handle
+        );
+        let handle: WebWindowHandle = bytemuck::Zeroable::zeroed();
+        assert_eq!(handle.scale_factor, 0.0);
+    }
+
+    #[cfg(feature = "raw-window-handle-0-5")]
+    #[test]
+    fn rwh_05_round_trip() {
+        let raw = RawWindowHandle::from(XlibWindowHandle::new(1).with_visual_id(2));
+        let old = ::rwh_05::RawWindowHandle::try_from(raw).unwrap();
+        assert_eq!(RawWindowHandle::try_from(old), Ok(raw));
+
+        let raw = RawWindowHandle::from(FuchsiaViewWindowHandle::new(NonNull::dangling()));
+        assert_eq!(::rwh_05::RawWindowHandle::try_from(raw), Err(raw));
+
+        let old = ::rwh_05::RawWindowHandle::Wayland(::rwh_05::WaylandWindowHandle::empty());
+        assert_eq!(RawWindowHandle::try_from(old), Err(old));
+
+        let raw = RawDisplayHandle::from(XcbDisplayHandle::new(None, 1));
+        let old = ::rwh_05::RawDisplayHandle::try_from(raw).unwrap();
+        assert_eq!(RawDisplayHandle::try_from(old), Ok(raw));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        use core::num::NonZeroU32;
+        use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+        let handle = RawWindowHandle::Xcb(XcbWindowHandle::new(NonZeroU32::new(7).unwrap()));
+        assert_tokens(
+            &handle,
+            &[
+                Token::NewtypeVariant {
+                    name: "RawWindowHandle",
+                    variant: "Xcb",
+                },
+                Token::Struct {
+                    name: "XcbWindowHandle",
+                    len: 4,
+                },
+                Token::Str("window"),
+                Token::U32(7),
+                Token::Str("visual_id"),
+                Token::None,
+                Token::Str("screen"),
+                Token::I32(0),
+                Token::Str("depth"),
+                Token::U8(0),
+                Token::StructEnd,
+            ],
+        );
+
+        let handle = RawDisplayHandle::Wayland(
+            WaylandDisplayHandle::new(NonNull::new(0x1000 as *mut _).unwrap())
+                .with_registry(NonNull::new(0x2000 as *mut _)),
+        );
+        assert_tokens(
+            &handle,
+            &[
+                Token::NewtypeVariant {
+                    name: "RawDisplayHandle",
+                    variant: "Wayland",
+                },
+                Token::Struct {
+                    name: "WaylandDisplayHandle",
+                    len: 3,
+                },
+                Token::Str("display"),
+                Token::U64(0x1000),
+                Token::Str("registry"),
+                Token::Some,
+                Token::U64(0x2000),
+                Token::Str("event_queue"),
+                Token::None,
+                Token::StructEnd,
+            ],
+        );
+
+        assert_de_tokens_error::<WaylandDisplayHandle>(
+            &[
+                Token::Struct {
+                    name: "WaylandDisplayHandle",
+                    len: 1,
+                },
+                Token::Str("display"),
+                Token::U64(0),
+            ],
+            "invalid value: integer `0`, expected a non-null address",
+        );
+
+        // The offset file descriptors are (de)serialized as their accessors return them.
+        let handle = RawDisplayHandle::Xcb(XcbDisplayHandle::new(None, 1).with_fd(0));
+        assert_tokens(
+            &handle,
+            &[
+                Token::NewtypeVariant {
+                    name: "RawDisplayHandle",
+                    variant: "Xcb",
+                },
+                Token::Struct {
+                    name: "XcbDisplayHandle",
+                    len: 3,
+                },
+                Token::Str("connection"),
+                Token::None,
+                Token::Str("screen"),
+                Token::I32(1),
+                Token::Str("fd"),
+                Token::Some,
+                Token::I32(0),
+                Token::StructEnd,
+            ],
+        );
+
+        let handle = RawDisplayHandle::Drm(
+            DrmDisplayHandle::new(3)
+                .with_lease(4)
+                .with_render_node(true),
+        );
+        assert_tokens(
+            &handle,
+            &[
+                Token::NewtypeVariant {
+                    name: "RawDisplayHandle",
+                    variant: "Drm",
+                },
+                Token::Struct {
+                    name: "DrmDisplayHandle",
+                    len: 3,
+                },
+                Token::Str("fd"),
+                Token::I32(3),
+                Token::Str("lease_fd"),
+                Token::Some,
+                Token::I32(4),
+                Token::Str("render_node"),
+                Token::Bool(true),
+                Token::StructEnd,
+            ],
+        );
+
+        assert_de_tokens_error::<DrmDisplayHandle>(
+            &[
+                Token::Struct {
+                    name: "DrmDisplayHandle",
+                    len: 3,
+                },
+                Token::Str("fd"),
+                Token::I32(3),
+                Token::Str("lease_fd"),
+                Token::Some,
+                Token::I32(-1),
+            ],
+            "invalid value: integer `-1`, expected a non-negative file descriptor",
+        );
     }
 
     #[allow(deprecated, unused)]
@@ -494,6 +2164,7 @@ mod tests {
         _: &dyn HasRawDisplayHandle,
         _: &dyn HasWindowHandle,
         _: &dyn HasDisplayHandle,
+        _: &dyn HasWindowAndDisplayHandle,
     ) {
     }
 }
@@ -0,0 +1,30 @@
+/// Raw window handle that explicitly refers to no window.
+///
+/// This may be used by windowing libraries that create their native window lazily, and need to
+/// hand out an infallible handle before the window exists. Consumers should treat it distinctly
+/// from a real handle, and must not create surfaces from it, see [`RawWindowHandle::is_null`].
+///
+/// [`RawWindowHandle::is_null`]: crate::RawWindowHandle::is_null
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[repr(C)]
+pub struct NullWindowHandle {}
+
+impl NullWindowHandle {
+    /// Create a new empty window handle.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use raw_window_handle::NullWindowHandle;
+    /// let handle = NullWindowHandle::new();
+    /// // Can also be used in constant expressions.
+    /// const HANDLE: NullWindowHandle = NullWindowHandle::new();
+    /// ```
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
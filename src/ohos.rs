@@ -21,7 +21,10 @@ use super::DisplayHandle;
 
 /// Raw display handle for OpenHarmony.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[repr(C)]
 pub struct OhosDisplayHandle {}
 
 impl OhosDisplayHandle {
@@ -34,7 +37,7 @@ impl OhosDisplayHandle {
     /// # use raw_window_handle::OhosDisplayHandle;
     /// let handle = OhosDisplayHandle::new();
     /// ```
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Self {}
     }
 }
@@ -61,8 +64,10 @@ impl DisplayHandle<'static> {
 
 /// Raw window handle for Ohos NDK.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OhosNdkWindowHandle {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::non_null"))]
     pub native_window: NonNull<c_void>,
 }
 
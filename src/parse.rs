@@ -0,0 +1,103 @@
+//! Parsing of [`RawWindowHandleKind`] and [`RawDisplayHandleKind`] from their names.
+
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+use crate::{RawDisplayHandleKind, RawWindowHandleKind};
+
+/// Check whether the `Debug` representation of `value` is equal to `name`, ignoring ASCII case.
+///
+/// The `Debug` representation of a kind is the name of its variant, so this avoids keeping a
+/// separate list of names that could get out of sync with the enums.
+fn debug_eq_ignore_ascii_case(value: &impl fmt::Debug, name: &str) -> bool {
+    struct Matcher<'a> {
+        rest: &'a str,
+    }
+
+    impl Write for Matcher<'_> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            match self.rest.get(..s.len()) {
+                Some(head) if head.eq_ignore_ascii_case(s) => {
+                    self.rest = &self.rest[s.len()..];
+                    Ok(())
+                }
+                _ => Err(fmt::Error),
+            }
+        }
+    }
+
+    let mut matcher = Matcher { rest: name };
+    write!(matcher, "{:?}", value).is_ok() && matcher.rest.is_empty()
+}
+
+/// An error returned when parsing a [`RawWindowHandleKind`] or a [`RawDisplayHandleKind`] from
+/// an unknown name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseKindError {
+    window: bool,
+}
+
+impl fmt::Display for ParseKindError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn list(f: &mut fmt::Formatter<'_>, names: &[impl fmt::Debug]) -> fmt::Result {
+            for (i, name) in names.iter().enumerate() {
+                if i != 0 {
+                    f.write_str(", ")?;
+                }
+                write!(f, "{:?}", name)?;
+            }
+            Ok(())
+        }
+
+        if self.window {
+            f.write_str("unknown window handle kind, expected one of: ")?;
+            list(f, RawWindowHandleKind::ALL)
+        } else {
+            f.write_str("unknown display handle kind, expected one of: ")?;
+            list(f, RawDisplayHandleKind::ALL)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for ParseKindError {}
+
+/// Parse a kind from the name of its variant, ignoring ASCII case.
+///
+/// # Example
+///
+/// ```
+/// # use raw_window_handle::RawWindowHandleKind;
+/// let kind: RawWindowHandleKind = "wayland".parse().unwrap();
+/// assert_eq!(kind, RawWindowHandleKind::Wayland);
+/// assert!("x12".parse::<RawWindowHandleKind>().is_err());
+/// ```
+impl FromStr for RawWindowHandleKind {
+    type Err = ParseKindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::all()
+            .find(|kind| debug_eq_ignore_ascii_case(kind, s))
+            .ok_or(ParseKindError { window: true })
+    }
+}
+
+/// Parse a kind from the name of its variant, ignoring ASCII case.
+///
+/// # Example
+///
+/// ```
+/// # use raw_window_handle::RawDisplayHandleKind;
+/// let kind: RawDisplayHandleKind = "XCB".parse().unwrap();
+/// assert_eq!(kind, RawDisplayHandleKind::Xcb);
+/// ```
+impl FromStr for RawDisplayHandleKind {
+    type Err = ParseKindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::all()
+            .find(|kind| debug_eq_ignore_ascii_case(kind, s))
+            .ok_or(ParseKindError { window: false })
+    }
+}
@@ -0,0 +1,58 @@
+use core::ffi::c_int;
+
+/// Raw window handle for the PlayStation 4 and PlayStation 5.
+///
+/// This refers to a video output port opened with `sceVideoOutOpen`.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[repr(C)]
+pub struct PlayStationWindowHandle {
+    /// The `SceVideoOut` handle, as returned by `sceVideoOutOpen`.
+    pub video_out_handle: c_int,
+    /// The index of the display that the video output is shown on. Defaults to `0`, the main
+    /// display.
+    pub display_index: u32,
+}
+
+impl PlayStationWindowHandle {
+    /// Create a new handle to a video output.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use raw_window_handle::PlayStationWindowHandle;
+    /// #
+    /// # #[cfg(only_for_showcase)]
+    /// let video_out_handle = unsafe {
+    ///     sceVideoOutOpen(user_id, SCE_VIDEO_OUT_BUS_TYPE_MAIN, 0, core::ptr::null())
+    /// };
+    /// # let video_out_handle = 1;
+    /// let handle = PlayStationWindowHandle::new(video_out_handle);
+    /// ```
+    pub fn new(video_out_handle: c_int) -> Self {
+        Self {
+            video_out_handle,
+            display_index: 0,
+        }
+    }
+
+    /// Set the index of the display that the video output is shown on.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use raw_window_handle::PlayStationWindowHandle;
+    /// #
+    /// let video_out_handle: i32;
+    /// # video_out_handle = 1;
+    /// let handle = PlayStationWindowHandle::new(video_out_handle).with_display_index(1);
+    /// ```
+    pub fn with_display_index(mut self, display_index: u32) -> Self {
+        self.display_index = display_index;
+        self
+    }
+}
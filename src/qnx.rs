@@ -0,0 +1,71 @@
+use core::ffi::c_void;
+use core::ptr::NonNull;
+
+/// Raw display handle for the QNX Screen Graphics Subsystem.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScreenDisplayHandle {
+    /// A pointer to a `screen_context_t`.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::non_null"))]
+    pub context: NonNull<c_void>,
+}
+
+impl ScreenDisplayHandle {
+    /// Create a new handle to a Screen context.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::ScreenDisplayHandle;
+    /// # #[allow(non_camel_case_types)]
+    /// # type screen_context_t = ();
+    /// #
+    /// let context: NonNull<screen_context_t>;
+    /// # context = NonNull::from(&());
+    /// let handle = ScreenDisplayHandle::new(context.cast());
+    /// ```
+    pub fn new(context: NonNull<c_void>) -> Self {
+        Self { context }
+    }
+}
+
+/// Raw window handle for the QNX Screen Graphics Subsystem.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScreenWindowHandle {
+    /// A pointer to a `screen_window_t`.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::non_null"))]
+    pub window: NonNull<c_void>,
+    /// A pointer to the `screen_context_t` that the window was created in.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::non_null"))]
+    pub context: NonNull<c_void>,
+}
+
+impl ScreenWindowHandle {
+    /// Create a new handle to a Screen window.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::ScreenWindowHandle;
+    /// # #[allow(non_camel_case_types)]
+    /// # type screen_window_t = ();
+    /// # #[allow(non_camel_case_types)]
+    /// # type screen_context_t = ();
+    /// #
+    /// let window: NonNull<screen_window_t>;
+    /// let context: NonNull<screen_context_t>;
+    /// # window = NonNull::from(&());
+    /// # context = NonNull::from(&());
+    /// let handle = ScreenWindowHandle::new(window.cast(), context.cast());
+    /// ```
+    pub fn new(window: NonNull<c_void>, context: NonNull<c_void>) -> Self {
+        Self { window, context }
+    }
+}
@@ -5,7 +5,10 @@ use super::DisplayHandle;
 
 /// Raw display handle for the Redox operating system.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[repr(C)]
 pub struct OrbitalDisplayHandle {}
 
 impl OrbitalDisplayHandle {
@@ -18,7 +21,7 @@ impl OrbitalDisplayHandle {
     /// # use raw_window_handle::OrbitalDisplayHandle;
     /// let handle = OrbitalDisplayHandle::new();
     /// ```
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Self {}
     }
 }
@@ -45,11 +48,13 @@ impl DisplayHandle<'static> {
 
 /// Raw window handle for the Redox operating system.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OrbitalWindowHandle {
     /// A pointer to an orbclient window.
     // TODO(madsmtm): I think this is a file descriptor, so perhaps it should
     // actually use `std::os::fd::RawFd`, or some sort of integer instead?
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::non_null"))]
     pub window: NonNull<c_void>,
 }
 
@@ -0,0 +1,259 @@
+//! Conversions to and from the handle types of `raw-window-handle` v0.5.
+//!
+//! This allows bridging windowing libraries and graphics libraries that depend on different
+//! versions of this crate within a single binary.
+//!
+//! Handles are mapped to the variant of the same name, with the following exceptions:
+//!
+//! - [`RawWindowHandle::WaylandSubsurface`] is converted to a v0.5 `Wayland` handle to its
+//!   `wl_surface`, and the subsurface and parent are dropped.
+//! - The remaining window handle variants that were added after v0.5 (`OhosNdk`, `WebCanvas`,
+//!   `WebOffscreenCanvas`, `Screen`, `Switch`, `AppKitMetalLayer`, `UiKitMetalLayer`,
+//!   `WebCanvasSelector`, `Fuchsia`, `WinUi`, `DmaBuf`, `PlayStation`, `Custom`, `Tizen`, `Sdl`
+//!   and `Null`) have no counterpart.
+//! - Likewise, the `Ohos`, `Screen`, `Vulkan`, `Switch`, `WebWorker` and `Headless` display
+//!   handle variants have no counterpart.
+//! - Fields that do not exist in v0.5 are dropped when converting to it, and are set to their
+//!   defaults when converting from it. The `ui_window` field of the v0.5 `UiKitWindowHandle` is
+//!   dropped.
+//!
+//! Converting a handle without a counterpart, or a v0.5 handle that has a null pointer or ID in a
+//! field that is required here, fails and returns the original handle.
+
+use core::num::{NonZeroIsize, NonZeroU32};
+use core::ptr::{self, NonNull};
+
+use crate::{
+    AndroidDisplayHandle, AndroidNdkWindowHandle, AppKitDisplayHandle, AppKitWindowHandle,
+    DrmDisplayHandle, DrmWindowHandle, GbmDisplayHandle, GbmWindowHandle, HaikuDisplayHandle,
+    HaikuWindowHandle, OrbitalDisplayHandle, OrbitalWindowHandle, RawDisplayHandle,
+    RawWindowHandle, UiKitDisplayHandle, UiKitWindowHandle, WaylandDisplayHandle,
+    WaylandWindowHandle, WebDisplayHandle, WebWindowHandle, Win32WindowHandle, WinRtWindowHandle,
+    WindowsDisplayHandle, XcbDisplayHandle, XcbWindowHandle, XlibDisplayHandle, XlibWindowHandle,
+};
+
+fn to_ptr<T>(ptr: Option<NonNull<T>>) -> *mut T {
+    ptr.map_or(ptr::null_mut(), NonNull::as_ptr)
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "raw-window-handle-0-5")))]
+/// Convert to a v0.5 window handle.
+///
+/// See the [module-level documentation][self] for how the variants are mapped.
+impl TryFrom<RawWindowHandle> for rwh_05::RawWindowHandle {
+    type Error = RawWindowHandle;
+
+    fn try_from(value: RawWindowHandle) -> Result<Self, Self::Error> {
+        Ok(match value {
+            RawWindowHandle::UiKit(h) => {
+                let mut handle = rwh_05::UiKitWindowHandle::empty();
+                handle.ui_view = h.ui_view.as_ptr();
+                handle.ui_view_controller = to_ptr(h.ui_view_controller);
+                Self::UiKit(handle)
+            }
+            RawWindowHandle::AppKit(h) => {
+                let mut handle = rwh_05::AppKitWindowHandle::empty();
+                handle.ns_view = h.ns_view.as_ptr();
+                handle.ns_window = to_ptr(h.ns_window);
+                Self::AppKit(handle)
+            }
+            RawWindowHandle::Orbital(h) => {
+                let mut handle = rwh_05::OrbitalWindowHandle::empty();
+                handle.window = h.window.as_ptr();
+                Self::Orbital(handle)
+            }
+            RawWindowHandle::Xlib(h) => {
+                let mut handle = rwh_05::XlibWindowHandle::empty();
+                handle.window = h.window;
+                handle.visual_id = h.visual_id;
+                Self::Xlib(handle)
+            }
+            RawWindowHandle::Xcb(h) => {
+                let mut handle = rwh_05::XcbWindowHandle::empty();
+                handle.window = h.window.get();
+                handle.visual_id = h.visual_id.map_or(0, NonZeroU32::get);
+                Self::Xcb(handle)
+            }
+            RawWindowHandle::Wayland(WaylandWindowHandle { surface, .. })
+            | RawWindowHandle::WaylandSubsurface(crate::WaylandSubsurfaceWindowHandle {
+                surface,
+                ..
+            }) => {
+                let mut handle = rwh_05::WaylandWindowHandle::empty();
+                handle.surface = surface.as_ptr();
+                Self::Wayland(handle)
+            }
+            RawWindowHandle::Drm(h) => {
+                let mut handle = rwh_05::DrmWindowHandle::empty();
+                handle.plane = h.plane;
+                Self::Drm(handle)
+            }
+            RawWindowHandle::Gbm(h) => {
+                let mut handle = rwh_05::GbmWindowHandle::empty();
+                handle.gbm_surface = h.gbm_surface.as_ptr();
+                Self::Gbm(handle)
+            }
+            RawWindowHandle::Win32(h) => {
+                let mut handle = rwh_05::Win32WindowHandle::empty();
+                handle.hwnd = h.hwnd.get() as *mut _;
+                handle.hinstance = h.hinstance.map_or(0, NonZeroIsize::get) as *mut _;
+                Self::Win32(handle)
+            }
+            RawWindowHandle::WinRt(h) => {
+                let mut handle = rwh_05::WinRtWindowHandle::empty();
+                handle.core_window = h.core_window.as_ptr();
+                Self::WinRt(handle)
+            }
+            RawWindowHandle::Web(h) => {
+                let mut handle = rwh_05::WebWindowHandle::empty();
+                handle.id = h.id;
+                Self::Web(handle)
+            }
+            RawWindowHandle::AndroidNdk(h) => {
+                let mut handle = rwh_05::AndroidNdkWindowHandle::empty();
+                handle.a_native_window = h.a_native_window.as_ptr();
+                Self::AndroidNdk(handle)
+            }
+            RawWindowHandle::Haiku(h) => {
+                let mut handle = rwh_05::HaikuWindowHandle::empty();
+                handle.b_window = h.b_window.as_ptr();
+                handle.b_direct_window = to_ptr(h.b_direct_window);
+                Self::Haiku(handle)
+            }
+            _ => return Err(value),
+        })
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "raw-window-handle-0-5")))]
+/// Convert from a v0.5 window handle.
+///
+/// See the [module-level documentation][self] for how the variants are mapped.
+impl TryFrom<rwh_05::RawWindowHandle> for RawWindowHandle {
+    type Error = rwh_05::RawWindowHandle;
+
+    fn try_from(value: rwh_05::RawWindowHandle) -> Result<Self, Self::Error> {
+        use rwh_05::RawWindowHandle as Old;
+
+        let handle = match value {
+            Old::UiKit(h) => NonNull::new(h.ui_view).map(|ui_view| {
+                UiKitWindowHandle::new(ui_view)
+                    .with_ui_view_controller(NonNull::new(h.ui_view_controller))
+                    .into()
+            }),
+            Old::AppKit(h) => NonNull::new(h.ns_view).map(|ns_view| {
+                AppKitWindowHandle::new(ns_view)
+                    .with_ns_window(NonNull::new(h.ns_window))
+                    .into()
+            }),
+            Old::Orbital(h) => NonNull::new(h.window).map(|w| OrbitalWindowHandle::new(w).into()),
+            Old::Xlib(h) => Some(
+                XlibWindowHandle::new(h.window)
+                    .with_visual_id(h.visual_id)
+                    .into(),
+            ),
+            Old::Xcb(h) => NonZeroU32::new(h.window).map(|window| {
+                XcbWindowHandle::new(window)
+                    .with_visual_id(NonZeroU32::new(h.visual_id))
+                    .into()
+            }),
+            Old::Wayland(h) => NonNull::new(h.surface).map(|s| WaylandWindowHandle::new(s).into()),
+            Old::Drm(h) => Some(DrmWindowHandle::new(h.plane).into()),
+            Old::Gbm(h) => NonNull::new(h.gbm_surface).map(|s| GbmWindowHandle::new(s).into()),
+            Old::Win32(h) => NonZeroIsize::new(h.hwnd as isize).map(|hwnd| {
+                Win32WindowHandle::new(hwnd)
+                    .with_hinstance(NonZeroIsize::new(h.hinstance as isize))
+                    .into()
+            }),
+            Old::WinRt(h) => NonNull::new(h.core_window).map(|w| WinRtWindowHandle::new(w).into()),
+            Old::Web(h) => Some(WebWindowHandle::new(h.id).into()),
+            Old::AndroidNdk(h) => {
+                NonNull::new(h.a_native_window).map(|w| AndroidNdkWindowHandle::new(w).into())
+            }
+            Old::Haiku(h) => NonNull::new(h.b_window).map(|b_window| {
+                HaikuWindowHandle::new(b_window)
+                    .with_b_direct_window(NonNull::new(h.b_direct_window))
+                    .into()
+            }),
+            _ => None,
+        };
+        handle.ok_or(value)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "raw-window-handle-0-5")))]
+/// Convert to a v0.5 display handle.
+///
+/// See the [module-level documentation][self] for how the variants are mapped.
+impl TryFrom<RawDisplayHandle> for rwh_05::RawDisplayHandle {
+    type Error = RawDisplayHandle;
+
+    fn try_from(value: RawDisplayHandle) -> Result<Self, Self::Error> {
+        Ok(match value {
+            RawDisplayHandle::UiKit(_) => Self::UiKit(rwh_05::UiKitDisplayHandle::empty()),
+            RawDisplayHandle::AppKit(_) => Self::AppKit(rwh_05::AppKitDisplayHandle::empty()),
+            RawDisplayHandle::Orbital(_) => Self::Orbital(rwh_05::OrbitalDisplayHandle::empty()),
+            RawDisplayHandle::Xlib(h) => {
+                let mut handle = rwh_05::XlibDisplayHandle::empty();
+                handle.display = to_ptr(h.display);
+                handle.screen = h.screen;
+                Self::Xlib(handle)
+            }
+            RawDisplayHandle::Xcb(h) => {
+                let mut handle = rwh_05::XcbDisplayHandle::empty();
+                handle.connection = to_ptr(h.connection);
+                handle.screen = h.screen;
+                Self::Xcb(handle)
+            }
+            RawDisplayHandle::Wayland(h) => {
+                let mut handle = rwh_05::WaylandDisplayHandle::empty();
+                handle.display = h.display.as_ptr();
+                Self::Wayland(handle)
+            }
+            RawDisplayHandle::Drm(h) => {
+                let mut handle = rwh_05::DrmDisplayHandle::empty();
+                handle.fd = h.fd;
+                Self::Drm(handle)
+            }
+            RawDisplayHandle::Gbm(h) => {
+                let mut handle = rwh_05::GbmDisplayHandle::empty();
+                handle.gbm_device = h.gbm_device.as_ptr();
+                Self::Gbm(handle)
+            }
+            RawDisplayHandle::Windows(_) => Self::Windows(rwh_05::WindowsDisplayHandle::empty()),
+            RawDisplayHandle::Web(_) => Self::Web(rwh_05::WebDisplayHandle::empty()),
+            RawDisplayHandle::Android(_) => Self::Android(rwh_05::AndroidDisplayHandle::empty()),
+            RawDisplayHandle::Haiku(_) => Self::Haiku(rwh_05::HaikuDisplayHandle::empty()),
+            _ => return Err(value),
+        })
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "raw-window-handle-0-5")))]
+/// Convert from a v0.5 display handle.
+///
+/// See the [module-level documentation][self] for how the variants are mapped.
+impl TryFrom<rwh_05::RawDisplayHandle> for RawDisplayHandle {
+    type Error = rwh_05::RawDisplayHandle;
+
+    fn try_from(value: rwh_05::RawDisplayHandle) -> Result<Self, Self::Error> {
+        use rwh_05::RawDisplayHandle as Old;
+
+        let handle = match value {
+            Old::UiKit(_) => Some(UiKitDisplayHandle::new().into()),
+            Old::AppKit(_) => Some(AppKitDisplayHandle::new().into()),
+            Old::Orbital(_) => Some(OrbitalDisplayHandle::new().into()),
+            Old::Xlib(h) => Some(XlibDisplayHandle::new(NonNull::new(h.display), h.screen).into()),
+            Old::Xcb(h) => Some(XcbDisplayHandle::new(NonNull::new(h.connection), h.screen).into()),
+            Old::Wayland(h) => NonNull::new(h.display).map(|d| WaylandDisplayHandle::new(d).into()),
+            Old::Drm(h) => Some(DrmDisplayHandle::new(h.fd).into()),
+            Old::Gbm(h) => NonNull::new(h.gbm_device).map(|d| GbmDisplayHandle::new(d).into()),
+            Old::Windows(_) => Some(WindowsDisplayHandle::new().into()),
+            Old::Web(_) => Some(WebDisplayHandle::new().into()),
+            Old::Android(_) => Some(AndroidDisplayHandle::new().into()),
+            Old::Haiku(_) => Some(HaikuDisplayHandle::new().into()),
+            _ => None,
+        };
+        handle.ok_or(value)
+    }
+}
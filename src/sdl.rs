@@ -0,0 +1,40 @@
+use core::ffi::c_void;
+use core::ptr::NonNull;
+
+/// Raw window handle for an SDL Metal view.
+///
+/// SDL creates its own view for Metal rendering, and hands it out as an opaque `SDL_MetalView`.
+/// This is available on both macOS and iOS. Use `SDL_Metal_GetLayer` to get the `CAMetalLayer`
+/// backing the view.
+///
+/// Note that, like other views, the Metal view should only be accessed from the main thread of
+/// the application. This struct is `!Send` and `!Sync` to help with ensuring that.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SdlMetalWindowHandle {
+    /// An `SDL_MetalView`, as returned by `SDL_Metal_CreateView`.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::non_null"))]
+    pub metal_view: NonNull<c_void>,
+}
+
+impl SdlMetalWindowHandle {
+    /// Create a new handle to an `SDL_MetalView`.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::SdlMetalWindowHandle;
+    /// # type SDL_MetalView = ();
+    /// #
+    /// # #[cfg(only_for_showcase)]
+    /// let metal_view = unsafe { SDL_Metal_CreateView(window) };
+    /// # let metal_view: NonNull<SDL_MetalView> = NonNull::from(&());
+    /// let handle = SdlMetalWindowHandle::new(metal_view.cast());
+    /// ```
+    pub fn new(metal_view: NonNull<c_void>) -> Self {
+        Self { metal_view }
+    }
+}
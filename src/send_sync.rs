@@ -0,0 +1,113 @@
+use core::ops::Deref;
+
+use crate::{RawDisplayHandle, RawWindowHandle};
+
+/// A [`RawWindowHandle`] that the creator has asserted is safe to send and share between threads.
+///
+/// [`RawWindowHandle`] is `!Send` and `!Sync`, since most of its variants contain pointers to
+/// objects that may only be used from a specific thread. Some handles are however plain integer
+/// identifiers that are only ever interpreted by the display server, and it can be useful to pass
+/// these to e.g. a thread pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SendSyncRawWindowHandle(RawWindowHandle);
+
+// SAFETY: Upheld by the caller of `SendSyncRawWindowHandle::new`.
+unsafe impl Send for SendSyncRawWindowHandle {}
+unsafe impl Sync for SendSyncRawWindowHandle {}
+
+impl SendSyncRawWindowHandle {
+    /// Wrap a [`RawWindowHandle`], asserting that it may be used from any thread.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that every field of the handle may be accessed from any thread. In
+    /// practice, this means:
+    ///
+    /// - [`RawWindowHandle::Xlib`] and [`RawWindowHandle::Xcb`]: The XID and visual ID are plain
+    ///   integers, and may be sent freely. Note that the X connection used with them still has its
+    ///   own thread-safety requirements.
+    /// - [`RawWindowHandle::Win32`]: The `HWND` may be sent, but note that many Win32 APIs may only
+    ///   be called on the thread that created the window.
+    /// - [`RawWindowHandle::Web`]: The ID is a plain integer, and may be sent freely.
+    /// - [`RawWindowHandle::Drm`]: The plane, connector and CRTC IDs are plain integers, and may be
+    ///   sent freely.
+    /// - [`RawWindowHandle::DmaBuf`]: The file descriptor may be sent freely.
+    /// - [`RawWindowHandle::PlayStation`]: The video output handle is a plain integer, and may be
+    ///   sent freely.
+    /// - [`RawWindowHandle::Null`]: No data is stored, so it may be sent freely.
+    /// - For all other variants, the caller must know that the pointed-to objects are themselves
+    ///   thread-safe. In particular, AppKit and UIKit views may only be accessed from the main
+    ///   thread, so wrapping such a handle is almost always incorrect.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use raw_window_handle::{RawWindowHandle, SendSyncRawWindowHandle, WebWindowHandle};
+    /// let raw = RawWindowHandle::from(WebWindowHandle::new(1));
+    /// // SAFETY: The web handle is a plain integer ID.
+    /// let handle = unsafe { SendSyncRawWindowHandle::new(raw) };
+    /// std::thread::spawn(move || {
+    ///     let _raw: RawWindowHandle = *handle;
+    /// });
+    /// ```
+    pub unsafe fn new(raw: RawWindowHandle) -> Self {
+        Self(raw)
+    }
+
+    /// Get the underlying raw window handle.
+    pub fn into_raw(self) -> RawWindowHandle {
+        self.0
+    }
+}
+
+impl Deref for SendSyncRawWindowHandle {
+    type Target = RawWindowHandle;
+
+    fn deref(&self) -> &RawWindowHandle {
+        &self.0
+    }
+}
+
+/// A [`RawDisplayHandle`] that the creator has asserted is safe to send and share between threads.
+///
+/// See [`SendSyncRawWindowHandle`] for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SendSyncRawDisplayHandle(RawDisplayHandle);
+
+// SAFETY: Upheld by the caller of `SendSyncRawDisplayHandle::new`.
+unsafe impl Send for SendSyncRawDisplayHandle {}
+unsafe impl Sync for SendSyncRawDisplayHandle {}
+
+impl SendSyncRawDisplayHandle {
+    /// Wrap a [`RawDisplayHandle`], asserting that it may be used from any thread.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that every field of the handle may be accessed from any thread. In
+    /// practice, this means:
+    ///
+    /// - Display handles without any fields (such as [`RawDisplayHandle::Windows`],
+    ///   [`RawDisplayHandle::Web`] or [`RawDisplayHandle::AppKit`]) may be sent freely.
+    /// - [`RawDisplayHandle::Drm`]: The file descriptors may be sent freely.
+    /// - [`RawDisplayHandle::Xlib`]: The `Display` may only be shared if `XInitThreads` was called
+    ///   before it was opened.
+    /// - [`RawDisplayHandle::Xcb`]: The `xcb_connection_t` is thread-safe, and may be sent freely.
+    /// - For all other variants, the caller must know that the pointed-to objects are themselves
+    ///   thread-safe.
+    pub unsafe fn new(raw: RawDisplayHandle) -> Self {
+        Self(raw)
+    }
+
+    /// Get the underlying raw display handle.
+    pub fn into_raw(self) -> RawDisplayHandle {
+        self.0
+    }
+}
+
+impl Deref for SendSyncRawDisplayHandle {
+    type Target = RawDisplayHandle;
+
+    fn deref(&self) -> &RawDisplayHandle {
+        &self.0
+    }
+}
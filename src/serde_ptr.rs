@@ -0,0 +1,100 @@
+//! Helpers for (de)serializing pointer fields as plain integer addresses.
+//!
+//! Pointers are written out as a `u64` address. Deserialized pointers are *not* validated in any
+//! way; it is entirely up to the user to ensure that they are still meaningful in the process that
+//! reads them back.
+//!
+//! This also holds the helpers for the private integer encodings of some handles, so that they
+//! are (de)serialized the same way as their public accessors.
+
+use core::ptr::NonNull;
+
+use serde::de::{Deserialize, Deserializer, Error, Unexpected};
+use serde::ser::Serialize;
+use serde::ser::Serializer;
+
+fn from_addr<'de, T, D: Deserializer<'de>>(addr: u64) -> Result<NonNull<T>, D::Error> {
+    let addr = usize::try_from(addr).map_err(|_| {
+        D::Error::invalid_value(Unexpected::Unsigned(addr), &"a pointer-sized address")
+    })?;
+    NonNull::new(addr as *mut T)
+        .ok_or_else(|| D::Error::invalid_value(Unexpected::Unsigned(0), &"a non-null address"))
+}
+
+pub(crate) mod non_null {
+    use super::*;
+
+    pub(crate) fn serialize<T, S: Serializer>(
+        ptr: &NonNull<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(ptr.as_ptr() as usize as u64)
+    }
+
+    pub(crate) fn deserialize<'de, T, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<NonNull<T>, D::Error> {
+        from_addr::<T, D>(u64::deserialize(deserializer)?)
+    }
+}
+
+pub(crate) mod option_non_null {
+    use super::*;
+
+    pub(crate) fn serialize<T, S: Serializer>(
+        ptr: &Option<NonNull<T>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match ptr {
+            Some(ptr) => serializer.serialize_some(&(ptr.as_ptr() as usize as u64)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(crate) fn deserialize<'de, T, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<NonNull<T>>, D::Error> {
+        Option::<u64>::deserialize(deserializer)?
+            .map(from_addr::<T, D>)
+            .transpose()
+    }
+}
+
+/// A file descriptor stored plus one, with 0 meaning none, as an `Option<i32>`.
+pub(crate) mod option_fd {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        fd_plus_one: &u32,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        fd_plus_one
+            .checked_sub(1)
+            .map(|fd| fd as i32)
+            .serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+        match Option::<i32>::deserialize(deserializer)? {
+            Some(fd) if fd < 0 => Err(D::Error::invalid_value(
+                Unexpected::Signed(fd.into()),
+                &"a non-negative file descriptor",
+            )),
+            Some(fd) => Ok(fd as u32 + 1),
+            None => Ok(0),
+        }
+    }
+}
+
+/// A flag stored as a non-zero integer, as a `bool`.
+pub(crate) mod flag {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(flag: &u32, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bool(*flag != 0)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+        Ok(bool::deserialize(deserializer)? as u32)
+    }
+}
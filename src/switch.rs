@@ -0,0 +1,77 @@
+use core::ffi::c_void;
+use core::ptr::NonNull;
+
+use super::DisplayHandle;
+
+/// Raw display handle for the Nintendo Switch.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[repr(C)]
+pub struct SwitchDisplayHandle {}
+
+impl SwitchDisplayHandle {
+    /// Create a new empty display handle.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use raw_window_handle::SwitchDisplayHandle;
+    /// let handle = SwitchDisplayHandle::new();
+    /// ```
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+impl DisplayHandle<'static> {
+    /// Create a Nintendo Switch-based display handle.
+    ///
+    /// As no data is borrowed by this handle, it is completely safe to create. This function
+    /// may be useful to windowing framework implementations that want to avoid unsafe code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use raw_window_handle::{DisplayHandle, HasDisplayHandle};
+    /// # fn do_something(rwh: impl HasDisplayHandle) { let _ = rwh; }
+    /// let handle = DisplayHandle::switch();
+    /// do_something(handle);
+    /// ```
+    pub fn switch() -> Self {
+        // SAFETY: No data is borrowed.
+        unsafe { Self::borrow_raw(SwitchDisplayHandle::new().into()) }
+    }
+}
+
+/// Raw window handle for the Nintendo Switch.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SwitchWindowHandle {
+    /// A pointer to an `nn::vi::NativeWindow`.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::non_null"))]
+    pub nwindow: NonNull<c_void>,
+}
+
+impl SwitchWindowHandle {
+    /// Create a new handle to an `nn::vi::NativeWindow`.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::SwitchWindowHandle;
+    /// # type NativeWindow = ();
+    /// #
+    /// let nwindow: NonNull<NativeWindow>;
+    /// # nwindow = NonNull::from(&());
+    /// let handle = SwitchWindowHandle::new(nwindow.cast());
+    /// ```
+    pub fn new(nwindow: NonNull<c_void>) -> Self {
+        Self { nwindow }
+    }
+}
@@ -0,0 +1,77 @@
+use core::num::{NonZeroIsize, NonZeroU32};
+use core::ptr::NonNull;
+
+use crate::{
+    AndroidNdkWindowHandle, AppKitMetalLayerWindowHandle, AppKitWindowHandle, CustomWindowHandle,
+    DmaBufWindowHandle, DrmWindowHandle, FuchsiaViewWindowHandle, GbmWindowHandle,
+    HaikuWindowHandle, NullWindowHandle, OhosNdkWindowHandle, OrbitalWindowHandle,
+    PlayStationWindowHandle, RawWindowHandle, RawWindowHandleKind, ScreenWindowHandle,
+    SdlMetalWindowHandle, SwitchWindowHandle, TizenWindowHandle, UiKitMetalLayerWindowHandle,
+    UiKitWindowHandle, WaylandSubsurfaceWindowHandle, WaylandWindowHandle,
+    WebCanvasSelectorWindowHandle, WebCanvasWindowHandle, WebOffscreenCanvasWindowHandle,
+    WebWindowHandle, Win32WindowHandle, WinRtWindowHandle, WinUiSwapChainPanelWindowHandle,
+    XcbWindowHandle, XlibWindowHandle,
+};
+
+#[cfg_attr(docsrs, doc(cfg(feature = "test-util")))]
+impl RawWindowHandle {
+    /// Create a placeholder handle of the given kind, for use in tests.
+    ///
+    /// All pointers in the handle are [dangling][NonNull::dangling], and all IDs are zero (or one,
+    /// where zero is not a valid value). Optional fields are left unset. The handle must therefore
+    /// never be passed to code that will actually use it; it is only useful for exercising code
+    /// that dispatches on the kind of handle.
+    ///
+    /// This is only available when the `test-util` feature is enabled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use raw_window_handle::{RawWindowHandle, RawWindowHandleKind};
+    /// let handle = RawWindowHandle::dummy(RawWindowHandleKind::Wayland);
+    /// assert_eq!(handle.kind(), RawWindowHandleKind::Wayland);
+    /// ```
+    pub fn dummy(kind: RawWindowHandleKind) -> Self {
+        let ptr = NonNull::dangling();
+        match kind {
+            RawWindowHandleKind::UiKit => UiKitWindowHandle::new(ptr).into(),
+            RawWindowHandleKind::AppKit => AppKitWindowHandle::new(ptr).into(),
+            RawWindowHandleKind::Orbital => OrbitalWindowHandle::new(ptr).into(),
+            RawWindowHandleKind::OhosNdk => OhosNdkWindowHandle::new(ptr).into(),
+            RawWindowHandleKind::Xlib => XlibWindowHandle::new(0).into(),
+            RawWindowHandleKind::Xcb => XcbWindowHandle::new(NonZeroU32::new(1).unwrap()).into(),
+            RawWindowHandleKind::Wayland => WaylandWindowHandle::new(ptr).into(),
+            RawWindowHandleKind::Drm => DrmWindowHandle::new(0).into(),
+            RawWindowHandleKind::Gbm => GbmWindowHandle::new(ptr).into(),
+            RawWindowHandleKind::Win32 => {
+                Win32WindowHandle::new(NonZeroIsize::new(1).unwrap()).into()
+            }
+            RawWindowHandleKind::WinRt => WinRtWindowHandle::new(ptr).into(),
+            RawWindowHandleKind::Web => WebWindowHandle::new(0).into(),
+            RawWindowHandleKind::WebCanvas => WebCanvasWindowHandle::new(ptr).into(),
+            RawWindowHandleKind::WebOffscreenCanvas => {
+                WebOffscreenCanvasWindowHandle::new(ptr).into()
+            }
+            RawWindowHandleKind::AndroidNdk => AndroidNdkWindowHandle::new(ptr).into(),
+            RawWindowHandleKind::Haiku => HaikuWindowHandle::new(ptr).into(),
+            RawWindowHandleKind::Screen => ScreenWindowHandle::new(ptr, ptr).into(),
+            RawWindowHandleKind::WaylandSubsurface => {
+                WaylandSubsurfaceWindowHandle::new(ptr, ptr, ptr).into()
+            }
+            RawWindowHandleKind::Switch => SwitchWindowHandle::new(ptr).into(),
+            RawWindowHandleKind::AppKitMetalLayer => AppKitMetalLayerWindowHandle::new(ptr).into(),
+            RawWindowHandleKind::UiKitMetalLayer => UiKitMetalLayerWindowHandle::new(ptr).into(),
+            RawWindowHandleKind::WebCanvasSelector => {
+                WebCanvasSelectorWindowHandle::new(NonNull::dangling(), 0).into()
+            }
+            RawWindowHandleKind::Fuchsia => FuchsiaViewWindowHandle::new(ptr).into(),
+            RawWindowHandleKind::WinUi => WinUiSwapChainPanelWindowHandle::new(ptr).into(),
+            RawWindowHandleKind::DmaBuf => DmaBufWindowHandle::new(0, 0, 0, 0).into(),
+            RawWindowHandleKind::PlayStation => PlayStationWindowHandle::new(0).into(),
+            RawWindowHandleKind::Custom => CustomWindowHandle::new(0, ptr).into(),
+            RawWindowHandleKind::Tizen => TizenWindowHandle::new(ptr).into(),
+            RawWindowHandleKind::Sdl => SdlMetalWindowHandle::new(ptr).into(),
+            RawWindowHandleKind::Null => NullWindowHandle::new().into(),
+        }
+    }
+}
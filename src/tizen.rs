@@ -0,0 +1,63 @@
+use core::ffi::c_void;
+use core::ptr::NonNull;
+
+/// Raw window handle for Tizen, using EFL.
+///
+/// Tizen applications draw into an `Evas_Object`, which is backed by a Wayland surface through
+/// Ecore_Wl2.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TizenWindowHandle {
+    /// A pointer to an `Evas_Object`.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::non_null"))]
+    pub evas_object: NonNull<c_void>,
+    /// A pointer to the underlying `wl_surface`, if it is known.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::option_non_null"))]
+    pub wl_surface: Option<NonNull<c_void>>,
+}
+
+impl TizenWindowHandle {
+    /// Create a new handle to an `Evas_Object`.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::TizenWindowHandle;
+    /// # type Evas_Object = ();
+    /// #
+    /// let evas_object: NonNull<Evas_Object>;
+    /// # evas_object = NonNull::from(&());
+    /// let handle = TizenWindowHandle::new(evas_object.cast());
+    /// ```
+    pub fn new(evas_object: NonNull<c_void>) -> Self {
+        Self {
+            evas_object,
+            wl_surface: None,
+        }
+    }
+
+    /// Set the underlying `wl_surface` of the `Evas_Object`.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::TizenWindowHandle;
+    /// # type Evas_Object = ();
+    /// #
+    /// let evas_object: NonNull<Evas_Object>;
+    /// # evas_object = NonNull::from(&());
+    /// # #[cfg(only_for_showcase)]
+    /// let wl_surface = unsafe { ecore_wl2_window_surface_get(ecore_wl2_window) };
+    /// # let wl_surface = NonNull::from(&()).cast();
+    /// let handle = TizenWindowHandle::new(evas_object.cast()).with_wl_surface(Some(wl_surface));
+    /// ```
+    pub fn with_wl_surface(mut self, wl_surface: Option<NonNull<c_void>>) -> Self {
+        self.wl_surface = wl_surface;
+        self
+    }
+}
@@ -5,7 +5,10 @@ use super::DisplayHandle;
 
 /// Raw display handle for UIKit.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[repr(C)]
 pub struct UiKitDisplayHandle {}
 
 impl UiKitDisplayHandle {
@@ -18,7 +21,7 @@ impl UiKitDisplayHandle {
     /// # use raw_window_handle::UiKitDisplayHandle;
     /// let handle = UiKitDisplayHandle::new();
     /// ```
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Self {}
     }
 }
@@ -83,12 +86,17 @@ impl DisplayHandle<'static> {
 /// # }
 /// ```
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UiKitWindowHandle {
     /// A pointer to an `UIView` object.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::non_null"))]
     pub ui_view: NonNull<c_void>,
     /// A pointer to an `UIViewController` object, if the view has one.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::option_non_null"))]
     pub ui_view_controller: Option<NonNull<c_void>>,
+    /// The class of the view's backing layer, if known.
+    pub layer_kind: UiKitLayerKind,
 }
 
 impl UiKitWindowHandle {
@@ -107,14 +115,133 @@ impl UiKitWindowHandle {
     ///
     /// let ui_view: Retained<UIView> = ...;
     /// let ui_view: NonNull<UIView> = NonNull::from(&*ui_view);
-    /// let mut handle = UiKitWindowHandle::new(ui_view.cast());
-    /// // Optionally, set the view controller too.
-    /// handle.ui_view_controller = None;
+    /// let handle = UiKitWindowHandle::new(ui_view.cast())
+    ///     // Optionally, set the view controller too.
+    ///     .with_ui_view_controller(None);
     /// ```
     pub fn new(ui_view: NonNull<c_void>) -> Self {
         Self {
             ui_view,
             ui_view_controller: None,
+            layer_kind: UiKitLayerKind::Unknown,
         }
     }
+
+    /// Set the `UIViewController` of the view.
+    ///
+    /// See [`new`][Self::new] for an example.
+    pub fn with_ui_view_controller(mut self, ui_view_controller: Option<NonNull<c_void>>) -> Self {
+        self.ui_view_controller = ui_view_controller;
+        self
+    }
+
+    /// Set the class of the view's backing layer.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::{UiKitLayerKind, UiKitWindowHandle};
+    /// # type UIView = ();
+    /// #
+    /// let ui_view: NonNull<UIView>;
+    /// # ui_view = NonNull::from(&());
+    /// let handle = UiKitWindowHandle::new(ui_view.cast()).with_layer_kind(UiKitLayerKind::Metal);
+    /// ```
+    pub fn with_layer_kind(mut self, layer_kind: UiKitLayerKind) -> Self {
+        self.layer_kind = layer_kind;
+        self
+    }
+}
+
+/// The class of the `CALayer` that backs the view of a [`UiKitWindowHandle`].
+///
+/// This allows renderers to choose between OpenGL ES and Metal without querying the view's
+/// `layerClass` through the Objective-C runtime.
+#[non_exhaustive]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UiKitLayerKind {
+    /// The class of the layer is not known.
+    #[default]
+    Unknown,
+    /// A `CAEAGLLayer`, used for OpenGL ES rendering.
+    Eagl,
+    /// A `CAMetalLayer`, used for Metal rendering.
+    Metal,
+}
+
+#[cfg(all(
+    target_vendor = "apple",
+    not(target_os = "macos"),
+    feature = "objc2-0-5"
+))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(
+        target_vendor = "apple",
+        not(target_os = "macos"),
+        feature = "objc2-0-5"
+    )))
+)]
+/// These implementations are only available when `objc2-0-5` is enabled.
+impl UiKitWindowHandle {
+    /// Create a new `UiKitWindowHandle` from an [`objc2_ui_kit::UIView`].
+    ///
+    /// The lifetime of the view should be at least as long as the lifetime of this.
+    pub fn from_ui_view(ui_view: &objc2_ui_kit::UIView) -> Self {
+        Self::new(NonNull::from(ui_view).cast())
+    }
+
+    /// Retain the underlying [`objc2_ui_kit::UIView`].
+    ///
+    /// # Safety
+    ///
+    /// The inner pointer must be valid. This is ensured if this handle was
+    /// borrowed from [`WindowHandle`][crate::WindowHandle].
+    pub unsafe fn retain_ui_view(&self) -> objc2::rc::Retained<objc2_ui_kit::UIView> {
+        // SAFETY: Upheld by the caller.
+        let ui_view = unsafe { objc2::rc::Retained::retain(self.ui_view.as_ptr().cast()) };
+        // Unwrap is fine, since the pointer came from `NonNull`.
+        ui_view.unwrap()
+    }
+}
+
+/// Raw window handle for a `CAMetalLayer` on UIKit.
+///
+/// This may be provided by windowing libraries that have already created a `CAMetalLayer` for the
+/// window, to save graphics libraries from having to create one from the view.
+///
+/// Note that, like the view itself, the layer should only be accessed from the main thread of the
+/// application. This struct is `!Send` and `!Sync` to help with ensuring that.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UiKitMetalLayerWindowHandle {
+    /// A pointer to a `CAMetalLayer` object.
+    ///
+    /// This must point to a valid `CAMetalLayer` for the lifetime of the handle.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::non_null"))]
+    pub layer: NonNull<c_void>,
+}
+
+impl UiKitMetalLayerWindowHandle {
+    /// Create a new handle to a `CAMetalLayer`.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::UiKitMetalLayerWindowHandle;
+    /// # type CAMetalLayer = ();
+    /// #
+    /// let layer: NonNull<CAMetalLayer>;
+    /// # layer = NonNull::from(&());
+    /// let handle = UiKitMetalLayerWindowHandle::new(layer.cast());
+    /// ```
+    pub fn new(layer: NonNull<c_void>) -> Self {
+        Self { layer }
+    }
 }
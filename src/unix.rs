@@ -4,12 +4,15 @@ use core::ptr::NonNull;
 
 /// Raw display handle for Xlib.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable))]
 pub struct XlibDisplayHandle {
     /// A pointer to an Xlib `Display`.
     ///
     /// It is strongly recommended to set this value, however it may be set to
     /// `None` to request the default display when using EGL.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::option_non_null"))]
     pub display: Option<NonNull<c_void>>,
 
     /// An X11 screen to use with this display handle.
@@ -40,16 +43,55 @@ impl XlibDisplayHandle {
     pub fn new(display: Option<NonNull<c_void>>, screen: c_int) -> Self {
         Self { display, screen }
     }
+
+    /// Get the pointer to the Xlib `Display`, if one was provided.
+    ///
+    /// This is the same as reading [`display`][Self::display], and is provided for symmetry with
+    /// [`Win32WindowHandle::hwnd_ptr`][crate::Win32WindowHandle::hwnd_ptr].
+    #[inline]
+    pub fn display_ptr(&self) -> Option<NonNull<c_void>> {
+        self.display
+    }
+}
+
+#[cfg(all(unix, feature = "x11-dl-2"))]
+#[cfg_attr(docsrs, doc(cfg(all(unix, feature = "x11-dl-2"))))]
+/// These implementations are only available when `x11-dl-2` is enabled.
+///
+/// Note that [`XlibWindowHandle::window`] is already an [`x11_dl::xlib::Window`], so no
+/// conversion is needed for the window handle itself.
+impl XlibDisplayHandle {
+    /// Create a new `XlibDisplayHandle` from an [`x11_dl::xlib::Display`], as returned by
+    /// `XOpenDisplay`.
+    ///
+    /// A null `display` is stored as `None`.
+    pub fn from_xlib_display(display: *mut x11_dl::xlib::Display, screen: c_int) -> Self {
+        Self::new(NonNull::new(display).map(NonNull::cast), screen)
+    }
+
+    /// Get the pointer to the Xlib `Display` as an [`x11_dl::xlib::Display`].
+    ///
+    /// Returns a null pointer if no display was provided.
+    pub fn xlib_display(&self) -> *mut x11_dl::xlib::Display {
+        self.display
+            .map_or(core::ptr::null_mut(), |display| display.as_ptr().cast())
+    }
 }
 
 /// Raw window handle for Xlib.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable))]
 pub struct XlibWindowHandle {
     /// An Xlib `Window`.
     pub window: c_ulong,
     /// An Xlib visual ID, or 0 if unknown.
     pub visual_id: c_ulong,
+    /// The X11 screen that the window resides on.
+    pub screen: c_int,
+    /// The depth of the window's visual in bits, or 0 if unknown.
+    pub depth: u8,
 }
 
 impl XlibWindowHandle {
@@ -64,26 +106,57 @@ impl XlibWindowHandle {
     /// #
     /// let window: c_ulong;
     /// # window = 0;
-    /// let mut handle = XlibWindowHandle::new(window);
-    /// // Optionally set the visual ID.
-    /// handle.visual_id = 0;
+    /// let handle = XlibWindowHandle::new(window)
+    ///     // Optionally set the visual ID, screen and depth.
+    ///     .with_visual_id(0)
+    ///     .with_screen(0)
+    ///     .with_depth(24);
     /// ```
     pub fn new(window: c_ulong) -> Self {
         Self {
             window,
             visual_id: 0,
+            screen: 0,
+            depth: 0,
         }
     }
+
+    /// Set the visual ID of the window.
+    ///
+    /// See [`new`][Self::new] for an example.
+    pub fn with_visual_id(mut self, visual_id: c_ulong) -> Self {
+        self.visual_id = visual_id;
+        self
+    }
+
+    /// Set the screen of the window.
+    ///
+    /// See [`new`][Self::new] for an example.
+    pub fn with_screen(mut self, screen: c_int) -> Self {
+        self.screen = screen;
+        self
+    }
+
+    /// Set the depth of the window's visual.
+    ///
+    /// See [`new`][Self::new] for an example.
+    pub fn with_depth(mut self, depth: u8) -> Self {
+        self.depth = depth;
+        self
+    }
 }
 
 /// Raw display handle for Xcb.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable))]
 pub struct XcbDisplayHandle {
     /// A pointer to an X server `xcb_connection_t`.
     ///
     /// It is strongly recommended to set this value, however it may be set to
     /// `None` to request the default display when using EGL.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::option_non_null"))]
     pub connection: Option<NonNull<c_void>>,
 
     /// An X11 screen to use with this display handle.
@@ -92,6 +165,16 @@ pub struct XcbDisplayHandle {
     /// graphics APIs could work only with one screen at the time,
     /// given that multiple screens usually reside on different GPUs.
     pub screen: c_int,
+
+    /// The file descriptor of the X server connection plus one, or 0 if unknown.
+    ///
+    /// This is offset by one so that a zeroed handle does not refer to file descriptor 0. Use
+    /// [`fd`][Self::fd] and [`with_fd`][Self::with_fd] to access it.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "fd", with = "crate::serde_ptr::option_fd")
+    )]
+    fd_plus_one: u32,
 }
 
 impl XcbDisplayHandle {
@@ -112,18 +195,93 @@ impl XcbDisplayHandle {
     /// let handle = XcbDisplayHandle::new(Some(connection), screen);
     /// ```
     pub fn new(connection: Option<NonNull<c_void>>, screen: c_int) -> Self {
-        Self { connection, screen }
+        Self {
+            connection,
+            screen,
+            fd_plus_one: 0,
+        }
+    }
+
+    /// Set the file descriptor of the X server connection, as returned by
+    /// `xcb_get_file_descriptor`.
+    ///
+    /// A negative `fd` marks the file descriptor as unknown.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::ffi::c_void;
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::XcbDisplayHandle;
+    /// #
+    /// let connection: NonNull<c_void>;
+    /// # connection = NonNull::from(&()).cast();
+    /// # #[cfg(only_for_showcase)]
+    /// let fd = unsafe { xcb_get_file_descriptor(connection.as_ptr()) };
+    /// # let fd = 3;
+    /// let handle = XcbDisplayHandle::new(Some(connection), 0).with_fd(fd);
+    /// assert_eq!(handle.fd(), Some(fd));
+    /// ```
+    pub fn with_fd(mut self, fd: c_int) -> Self {
+        self.fd_plus_one = if fd >= 0 { fd as u32 + 1 } else { 0 };
+        self
+    }
+
+    /// Get the file descriptor of the X server connection, or `None` if it is unknown.
+    ///
+    /// See [`with_fd`][Self::with_fd] for an example.
+    pub fn fd(&self) -> Option<c_int> {
+        self.fd_plus_one.checked_sub(1).map(|fd| fd as c_int)
+    }
+
+    /// Get the pointer to the `xcb_connection_t`, if one was provided.
+    ///
+    /// This is the same as reading [`connection`][Self::connection], and is provided for symmetry
+    /// with [`Win32WindowHandle::hwnd_ptr`][crate::Win32WindowHandle::hwnd_ptr].
+    #[inline]
+    pub fn connection_ptr(&self) -> Option<NonNull<c_void>> {
+        self.connection
+    }
+}
+
+#[cfg(all(unix, feature = "x11-dl-2"))]
+#[cfg_attr(docsrs, doc(cfg(all(unix, feature = "x11-dl-2"))))]
+/// These implementations are only available when `x11-dl-2` is enabled.
+impl XcbDisplayHandle {
+    /// Create a new `XcbDisplayHandle` from an [`x11_dl::xlib_xcb::xcb_connection_t`], such as
+    /// the one returned by `XGetXCBConnection`.
+    ///
+    /// A null `connection` is stored as `None`.
+    pub fn from_xcb_connection(
+        connection: *mut x11_dl::xlib_xcb::xcb_connection_t,
+        screen: c_int,
+    ) -> Self {
+        Self::new(NonNull::new(connection), screen)
+    }
+
+    /// Get the pointer to the [`x11_dl::xlib_xcb::xcb_connection_t`].
+    ///
+    /// Returns a null pointer if no connection was provided.
+    pub fn xcb_connection(&self) -> *mut x11_dl::xlib_xcb::xcb_connection_t {
+        self.connection
+            .map_or(core::ptr::null_mut(), |connection| connection.as_ptr())
     }
 }
 
 /// Raw window handle for Xcb.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct XcbWindowHandle {
     /// An X11 `xcb_window_t`.
     pub window: NonZeroU32, // Based on xproto.h
     /// An X11 `xcb_visualid_t`.
     pub visual_id: Option<NonZeroU32>,
+    /// The X11 screen that the window resides on.
+    pub screen: c_int,
+    /// The depth of the window's visual in bits, or 0 if unknown.
+    pub depth: u8,
 }
 
 impl XcbWindowHandle {
@@ -138,24 +296,65 @@ impl XcbWindowHandle {
     /// #
     /// let window: NonZeroU32;
     /// # window = NonZeroU32::new(1).unwrap();
-    /// let mut handle = XcbWindowHandle::new(window);
-    /// // Optionally set the visual ID.
-    /// handle.visual_id = None;
+    /// let handle = XcbWindowHandle::new(window)
+    ///     // Optionally set the visual ID, screen and depth.
+    ///     .with_visual_id(None)
+    ///     .with_screen(0)
+    ///     .with_depth(24);
     /// ```
     pub fn new(window: NonZeroU32) -> Self {
         Self {
             window,
             visual_id: None,
+            screen: 0,
+            depth: 0,
         }
     }
+
+    /// Set the visual ID of the window.
+    ///
+    /// See [`new`][Self::new] for an example.
+    pub fn with_visual_id(mut self, visual_id: Option<NonZeroU32>) -> Self {
+        self.visual_id = visual_id;
+        self
+    }
+
+    /// Set the screen of the window.
+    ///
+    /// See [`new`][Self::new] for an example.
+    pub fn with_screen(mut self, screen: c_int) -> Self {
+        self.screen = screen;
+        self
+    }
+
+    /// Set the depth of the window's visual.
+    ///
+    /// See [`new`][Self::new] for an example.
+    pub fn with_depth(mut self, depth: u8) -> Self {
+        self.depth = depth;
+        self
+    }
 }
 
 /// Raw display handle for Wayland.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WaylandDisplayHandle {
     /// A pointer to a `wl_display`.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::non_null"))]
     pub display: NonNull<c_void>,
+    /// A pointer to the `wl_registry` of the display, if the windowing system has one.
+    ///
+    /// This may be used to bind globals without another `wl_display_get_registry` round-trip.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::option_non_null"))]
+    pub registry: Option<NonNull<c_void>>,
+    /// A pointer to the `wl_event_queue` that the windowing system dispatches the surface's events
+    /// on, if it is not the default queue of the display.
+    ///
+    /// This allows dispatching on the correct queue with `wl_display_dispatch_queue`.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::option_non_null"))]
+    pub event_queue: Option<NonNull<c_void>>,
 }
 
 impl WaylandDisplayHandle {
@@ -174,16 +373,111 @@ impl WaylandDisplayHandle {
     /// let handle = WaylandDisplayHandle::new(display);
     /// ```
     pub fn new(display: NonNull<c_void>) -> Self {
-        Self { display }
+        Self {
+            display,
+            registry: None,
+            event_queue: None,
+        }
+    }
+
+    /// Set the `wl_registry` of the display.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::ffi::c_void;
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::WaylandDisplayHandle;
+    /// #
+    /// let display: NonNull<c_void>;
+    /// let registry: NonNull<c_void>;
+    /// # display = NonNull::from(&()).cast();
+    /// # registry = NonNull::from(&()).cast();
+    /// let handle = WaylandDisplayHandle::new(display).with_registry(Some(registry));
+    /// ```
+    pub fn with_registry(mut self, registry: Option<NonNull<c_void>>) -> Self {
+        self.registry = registry;
+        self
+    }
+
+    /// Set the `wl_event_queue` that the windowing system uses for the surface.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::ffi::c_void;
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::WaylandDisplayHandle;
+    /// #
+    /// let display: NonNull<c_void>;
+    /// # display = NonNull::from(&()).cast();
+    /// # #[cfg(only_for_showcase)]
+    /// let event_queue = unsafe { wl_display_create_queue(display) };
+    /// # let event_queue = NonNull::from(&()).cast();
+    /// let handle = WaylandDisplayHandle::new(display).with_event_queue(Some(event_queue));
+    /// ```
+    pub fn with_event_queue(mut self, event_queue: Option<NonNull<c_void>>) -> Self {
+        self.event_queue = event_queue;
+        self
+    }
+
+    /// Create a new display handle from a typed pointer to a `wl_display`.
+    ///
+    /// This is equivalent to [`new`][Self::new], but avoids having to cast the pointer first. The
+    /// pointer must refer to a `wl_display`, such as `wayland_sys::client::wl_display`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::WaylandDisplayHandle;
+    /// # #[allow(non_camel_case_types)]
+    /// # enum wl_display {}
+    /// let display: NonNull<wl_display>;
+    /// # display = NonNull::dangling();
+    /// let handle = WaylandDisplayHandle::from_wl_display(display);
+    /// assert_eq!(handle.wl_display::<wl_display>(), display);
+    /// ```
+    pub fn from_wl_display<T>(display: NonNull<T>) -> Self {
+        Self::new(display.cast())
+    }
+
+    /// Get the `wl_display` as a typed pointer.
+    ///
+    /// See [`from_wl_display`][Self::from_wl_display] for an example.
+    pub fn wl_display<T>(&self) -> NonNull<T> {
+        self.display.cast()
     }
 }
 
 /// Raw window handle for Wayland.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WaylandWindowHandle {
     /// A pointer to a `wl_surface`.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::non_null"))]
     pub surface: NonNull<c_void>,
+    /// The buffer scale of the surface, as set with `wl_surface.set_buffer_scale`. Defaults to
+    /// `1`.
+    pub buffer_scale: i32,
+    /// A pointer to the `wp_viewport` of the surface, if the windowing system created one.
+    ///
+    /// When using fractional scaling with `wp_viewporter`, the destination size of the viewport
+    /// should be used instead of [`buffer_scale`][Self::buffer_scale] when sizing buffers.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::option_non_null"))]
+    pub viewport: Option<NonNull<c_void>>,
+    /// A pointer to the `xdg_surface` of the surface, if it has one.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::option_non_null"))]
+    pub xdg_surface: Option<NonNull<c_void>>,
+    /// A pointer to the `xdg_toplevel` of the surface, if it has the `xdg_toplevel` role.
+    ///
+    /// This may be used with protocols that operate on toplevels, such as server-side decorations
+    /// with `xdg-decoration`, or to initiate interactive moves and resizes.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::option_non_null"))]
+    pub xdg_toplevel: Option<NonNull<c_void>>,
 }
 
 impl WaylandWindowHandle {
@@ -202,17 +496,189 @@ impl WaylandWindowHandle {
     /// let handle = WaylandWindowHandle::new(surface);
     /// ```
     pub fn new(surface: NonNull<c_void>) -> Self {
-        Self { surface }
+        Self {
+            surface,
+            buffer_scale: 1,
+            viewport: None,
+            xdg_surface: None,
+            xdg_toplevel: None,
+        }
+    }
+
+    /// Set the buffer scale of the surface.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::ffi::c_void;
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::WaylandWindowHandle;
+    /// #
+    /// let surface: NonNull<c_void>;
+    /// let viewport: NonNull<c_void>;
+    /// # surface = NonNull::from(&()).cast();
+    /// # viewport = NonNull::from(&()).cast();
+    /// let handle = WaylandWindowHandle::new(surface)
+    ///     .with_buffer_scale(2)
+    ///     .with_viewport(Some(viewport));
+    /// ```
+    pub fn with_buffer_scale(mut self, buffer_scale: i32) -> Self {
+        self.buffer_scale = buffer_scale;
+        self
+    }
+
+    /// Set the `wp_viewport` of the surface.
+    ///
+    /// See [`with_buffer_scale`][Self::with_buffer_scale] for an example.
+    pub fn with_viewport(mut self, viewport: Option<NonNull<c_void>>) -> Self {
+        self.viewport = viewport;
+        self
+    }
+
+    /// Set the `xdg_surface` of the surface.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::ffi::c_void;
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::WaylandWindowHandle;
+    /// #
+    /// let surface: NonNull<c_void>;
+    /// let xdg_surface: NonNull<c_void>;
+    /// let xdg_toplevel: NonNull<c_void>;
+    /// # surface = NonNull::from(&()).cast();
+    /// # xdg_surface = NonNull::from(&()).cast();
+    /// # xdg_toplevel = NonNull::from(&()).cast();
+    /// let handle = WaylandWindowHandle::new(surface)
+    ///     .with_xdg_surface(Some(xdg_surface))
+    ///     .with_xdg_toplevel(Some(xdg_toplevel));
+    /// ```
+    pub fn with_xdg_surface(mut self, xdg_surface: Option<NonNull<c_void>>) -> Self {
+        self.xdg_surface = xdg_surface;
+        self
+    }
+
+    /// Set the `xdg_toplevel` of the surface.
+    ///
+    /// See [`with_xdg_surface`][Self::with_xdg_surface] for an example.
+    pub fn with_xdg_toplevel(mut self, xdg_toplevel: Option<NonNull<c_void>>) -> Self {
+        self.xdg_toplevel = xdg_toplevel;
+        self
+    }
+
+    /// Create a new handle from a typed pointer to a `wl_surface`.
+    ///
+    /// This is equivalent to [`new`][Self::new], but avoids having to cast the pointer first. The
+    /// pointer must refer to a `wl_surface`, such as the `wayland_sys::client::wl_proxy` of a
+    /// `WlSurface`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::WaylandWindowHandle;
+    /// # #[allow(non_camel_case_types)]
+    /// # enum wl_proxy {}
+    /// let surface: NonNull<wl_proxy>;
+    /// # surface = NonNull::dangling();
+    /// let handle = WaylandWindowHandle::from_wl_surface(surface);
+    /// assert_eq!(handle.wl_surface::<wl_proxy>(), surface);
+    /// ```
+    pub fn from_wl_surface<T>(surface: NonNull<T>) -> Self {
+        Self::new(surface.cast())
+    }
+
+    /// Get the `wl_surface` as a typed pointer.
+    ///
+    /// See [`from_wl_surface`][Self::from_wl_surface] for an example.
+    pub fn wl_surface<T>(&self) -> NonNull<T> {
+        self.surface.cast()
+    }
+}
+
+/// Raw window handle for a Wayland subsurface.
+///
+/// Unlike [`WaylandWindowHandle`], this describes a `wl_surface` that has been given the
+/// `wl_subsurface` role, and is positioned relative to a parent surface.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WaylandSubsurfaceWindowHandle {
+    /// A pointer to the `wl_surface` of the subsurface.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::non_null"))]
+    pub surface: NonNull<c_void>,
+    /// A pointer to the `wl_subsurface`.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::non_null"))]
+    pub subsurface: NonNull<c_void>,
+    /// A pointer to the parent `wl_surface`.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::non_null"))]
+    pub parent: NonNull<c_void>,
+}
+
+impl WaylandSubsurfaceWindowHandle {
+    /// Create a new handle to a subsurface.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::ffi::c_void;
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::WaylandSubsurfaceWindowHandle;
+    /// #
+    /// let surface: NonNull<c_void>;
+    /// let subsurface: NonNull<c_void>;
+    /// let parent: NonNull<c_void>;
+    /// # surface = NonNull::from(&()).cast();
+    /// # subsurface = NonNull::from(&()).cast();
+    /// # parent = NonNull::from(&()).cast();
+    /// let handle = WaylandSubsurfaceWindowHandle::new(surface, subsurface, parent);
+    /// ```
+    pub fn new(
+        surface: NonNull<c_void>,
+        subsurface: NonNull<c_void>,
+        parent: NonNull<c_void>,
+    ) -> Self {
+        Self {
+            surface,
+            subsurface,
+            parent,
+        }
     }
 }
 
 /// Raw display handle for the Linux Kernel Mode Set/Direct Rendering Manager.
+///
+/// To provide a `gbm_device` that was created from the file descriptor, use a
+/// [`GbmDisplayHandle`] instead. DRM windows are [compatible][crate::backends_match] with both.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[repr(C)]
 pub struct DrmDisplayHandle {
     /// The drm file descriptor.
     // TODO: Use `std::os::fd::RawFd`?
     pub fd: i32,
+    /// The file descriptor of the DRM lease plus one, or 0 if the display is not leased.
+    ///
+    /// This is offset by one so that a zeroed handle is not leased. Use
+    /// [`lease_fd`][Self::lease_fd] and [`with_lease`][Self::with_lease] to access it.
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "lease_fd", with = "crate::serde_ptr::option_fd")
+    )]
+    lease_fd_plus_one: u32,
+    /// Non-zero if [`fd`][Self::fd] refers to a render node.
+    ///
+    /// This is an integer rather than a `bool` so that the handle can be `Pod`. Use
+    /// [`is_render_node`][Self::is_render_node] and [`with_render_node`][Self::with_render_node]
+    /// to access it.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::flag"))]
+    render_node: u32,
 }
 
 impl DrmDisplayHandle {
@@ -229,16 +695,92 @@ impl DrmDisplayHandle {
     /// let handle = DrmDisplayHandle::new(fd);
     /// ```
     pub fn new(fd: i32) -> Self {
-        Self { fd }
+        Self {
+            fd,
+            lease_fd_plus_one: 0,
+            render_node: 0,
+        }
+    }
+
+    /// Set the DRM lease file descriptor.
+    ///
+    /// A leased display only grants access to the resources that are part of the lease, such as
+    /// when a VR compositor is handed a headset's connector by the desktop compositor. A negative
+    /// `lease_fd` marks the display as not leased.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use raw_window_handle::DrmDisplayHandle;
+    /// #
+    /// let (fd, lease_fd): (i32, i32);
+    /// # (fd, lease_fd) = (0, 1);
+    /// let handle = DrmDisplayHandle::new(fd).with_lease(lease_fd);
+    /// assert_eq!(handle.lease_fd(), Some(lease_fd));
+    /// ```
+    pub fn with_lease(mut self, lease_fd: i32) -> Self {
+        self.lease_fd_plus_one = if lease_fd >= 0 {
+            lease_fd as u32 + 1
+        } else {
+            0
+        };
+        self
+    }
+
+    /// Get the file descriptor of the DRM lease, or `None` if the display is not leased.
+    ///
+    /// See [`with_lease`][Self::with_lease] for an example.
+    pub fn lease_fd(&self) -> Option<i32> {
+        self.lease_fd_plus_one.checked_sub(1).map(|fd| fd as i32)
+    }
+
+    /// Mark the file descriptor as a render node.
+    ///
+    /// Render nodes (such as `/dev/dri/renderD128`) can only be used for rendering, and not for
+    /// mode-setting like primary nodes (such as `/dev/dri/card0`).
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use raw_window_handle::DrmDisplayHandle;
+    /// #
+    /// let fd: i32; // open("/dev/dri/renderD128")
+    /// # fd = 0;
+    /// let handle = DrmDisplayHandle::new(fd).with_render_node(true);
+    /// assert!(handle.is_render_node());
+    /// ```
+    pub fn with_render_node(mut self, is_render_node: bool) -> Self {
+        self.render_node = is_render_node as u32;
+        self
+    }
+
+    /// Whether the file descriptor refers to a render node rather than a primary node.
+    ///
+    /// See [`with_render_node`][Self::with_render_node] for an example.
+    pub fn is_render_node(&self) -> bool {
+        self.render_node != 0
     }
 }
 
 /// Raw window handle for the Linux Kernel Mode Set/Direct Rendering Manager.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[repr(C)]
 pub struct DrmWindowHandle {
     /// The primary drm plane handle.
     pub plane: u32,
+    /// The drm connector ID, or 0 if unknown.
+    ///
+    /// If this is 0, the consumer must discover the connector itself.
+    pub connector_id: u32,
+    /// The drm CRTC ID, or 0 if unknown.
+    ///
+    /// If this is 0, the consumer must discover the CRTC itself.
+    pub crtc_id: u32,
 }
 
 impl DrmWindowHandle {
@@ -255,15 +797,51 @@ impl DrmWindowHandle {
     /// let handle = DrmWindowHandle::new(plane);
     /// ```
     pub fn new(plane: u32) -> Self {
-        Self { plane }
+        Self {
+            plane,
+            connector_id: 0,
+            crtc_id: 0,
+        }
+    }
+
+    /// Set the connector ID of the window.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use raw_window_handle::DrmWindowHandle;
+    /// #
+    /// let (plane, connector_id, crtc_id): (u32, u32, u32);
+    /// # (plane, connector_id, crtc_id) = (31, 42, 53);
+    /// let handle = DrmWindowHandle::new(plane)
+    ///     .with_connector(connector_id)
+    ///     .with_crtc(crtc_id);
+    /// ```
+    pub fn with_connector(mut self, connector_id: u32) -> Self {
+        self.connector_id = connector_id;
+        self
+    }
+
+    /// Set the CRTC ID of the window.
+    ///
+    /// See [`with_connector`][Self::with_connector] for an example.
+    pub fn with_crtc(mut self, crtc_id: u32) -> Self {
+        self.crtc_id = crtc_id;
+        self
     }
 }
 
 /// Raw display handle for the Linux Generic Buffer Manager.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GbmDisplayHandle {
     /// The gbm device.
+    ///
+    /// This is a `gbm_device *`. See [`gbm_device_ptr`][Self::gbm_device_ptr] for getting a
+    /// typed pointer.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::non_null"))]
     pub gbm_device: NonNull<c_void>,
 }
 
@@ -285,14 +863,55 @@ impl GbmDisplayHandle {
     pub fn new(gbm_device: NonNull<c_void>) -> Self {
         Self { gbm_device }
     }
+
+    /// Create a new display handle from a typed pointer to a `gbm_device`.
+    ///
+    /// This is equivalent to [`new`][Self::new], but avoids having to cast the pointer first. The
+    /// pointer must refer to a `gbm_device`, such as `gbm::ffi::gbm_device` from the [`gbm`]
+    /// crate.
+    ///
+    /// [`gbm`]: https://crates.io/crates/gbm
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::GbmDisplayHandle;
+    /// # #[allow(non_camel_case_types)]
+    /// # enum gbm_device {}
+    /// let device: NonNull<gbm_device>;
+    /// # device = NonNull::dangling();
+    /// let handle = GbmDisplayHandle::from_gbm_device(device);
+    /// assert_eq!(handle.gbm_device_ptr::<gbm_device>(), device);
+    /// ```
+    pub fn from_gbm_device<T>(gbm_device: NonNull<T>) -> Self {
+        Self::new(gbm_device.cast())
+    }
+
+    /// Get the `gbm_device` as a typed pointer.
+    ///
+    /// See [`from_gbm_device`][Self::from_gbm_device] for an example.
+    pub fn gbm_device_ptr<T>(&self) -> NonNull<T> {
+        self.gbm_device.cast()
+    }
 }
 
 /// Raw window handle for the Linux Generic Buffer Manager.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GbmWindowHandle {
     /// The gbm surface.
+    ///
+    /// This is a `gbm_surface *`. See [`gbm_surface_ptr`][Self::gbm_surface_ptr] for getting a
+    /// typed pointer.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::non_null"))]
     pub gbm_surface: NonNull<c_void>,
+    /// The fourcc format of the surface's buffers, or `DRM_FORMAT_INVALID` (0) if unspecified.
+    pub format: u32,
+    /// The DRM format modifier of the surface's buffers, or `DRM_FORMAT_MOD_INVALID`
+    /// (`0x00ff_ffff_ffff_ffff`) if unspecified.
+    pub modifier: u64,
 }
 
 impl GbmWindowHandle {
@@ -311,6 +930,133 @@ impl GbmWindowHandle {
     /// let handle = GbmWindowHandle::new(ptr);
     /// ```
     pub fn new(gbm_surface: NonNull<c_void>) -> Self {
-        Self { gbm_surface }
+        Self {
+            gbm_surface,
+            format: 0,
+            modifier: 0x00ff_ffff_ffff_ffff,
+        }
+    }
+
+    /// Create a new handle from a typed pointer to a `gbm_surface`.
+    ///
+    /// This is equivalent to [`new`][Self::new], but avoids having to cast the pointer first. The
+    /// pointer must refer to a `gbm_surface`, such as `gbm::ffi::gbm_surface` from the [`gbm`]
+    /// crate.
+    ///
+    /// [`gbm`]: https://crates.io/crates/gbm
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::GbmWindowHandle;
+    /// # #[allow(non_camel_case_types)]
+    /// # enum gbm_surface {}
+    /// let surface: NonNull<gbm_surface>;
+    /// # surface = NonNull::dangling();
+    /// let handle = GbmWindowHandle::from_gbm_surface(surface);
+    /// assert_eq!(handle.gbm_surface_ptr::<gbm_surface>(), surface);
+    /// ```
+    pub fn from_gbm_surface<T>(gbm_surface: NonNull<T>) -> Self {
+        Self::new(gbm_surface.cast())
+    }
+
+    /// Get the `gbm_surface` as a typed pointer.
+    ///
+    /// See [`from_gbm_surface`][Self::from_gbm_surface] for an example.
+    pub fn gbm_surface_ptr<T>(&self) -> NonNull<T> {
+        self.gbm_surface.cast()
+    }
+
+    /// Set the fourcc format of the surface.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::ffi::c_void;
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::GbmWindowHandle;
+    /// #
+    /// const DRM_FORMAT_XRGB8888: u32 = u32::from_le_bytes(*b"XR24");
+    /// const DRM_FORMAT_MOD_LINEAR: u64 = 0;
+    ///
+    /// let ptr: NonNull<c_void>;
+    /// # ptr = NonNull::from(&()).cast();
+    /// let handle = GbmWindowHandle::new(ptr)
+    ///     .with_format(DRM_FORMAT_XRGB8888)
+    ///     .with_modifier(DRM_FORMAT_MOD_LINEAR);
+    /// ```
+    pub fn with_format(mut self, format: u32) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Set the DRM format modifier of the surface.
+    ///
+    /// See [`with_format`][Self::with_format] for an example.
+    pub fn with_modifier(mut self, modifier: u64) -> Self {
+        self.modifier = modifier;
+        self
+    }
+}
+
+/// Raw window handle for a Linux dma-buf.
+///
+/// This is used when importing a buffer directly into a graphics API without any windowing system,
+/// e.g. with `EGL_EXT_image_dma_buf_import` or `VK_EXT_external_memory_dma_buf`. Only single-plane
+/// buffers are currently supported.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[repr(C)]
+pub struct DmaBufWindowHandle {
+    /// The dma-buf file descriptor.
+    pub fd: c_int,
+    /// The fourcc format of the buffer.
+    pub format: u32,
+    /// The DRM format modifier of the buffer, or `DRM_FORMAT_MOD_INVALID`
+    /// (`0x00ff_ffff_ffff_ffff`) if unspecified.
+    pub modifier: u64,
+    /// The width of the buffer in pixels.
+    pub width: u32,
+    /// The height of the buffer in pixels.
+    pub height: u32,
+}
+
+impl DmaBufWindowHandle {
+    /// Create a new handle to a dma-buf.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use raw_window_handle::DmaBufWindowHandle;
+    /// #
+    /// const DRM_FORMAT_XRGB8888: u32 = u32::from_le_bytes(*b"XR24");
+    /// const DRM_FORMAT_MOD_LINEAR: u64 = 0;
+    ///
+    /// let fd: i32;
+    /// # fd = 0;
+    /// let handle = DmaBufWindowHandle::new(fd, 1920, 1080, DRM_FORMAT_XRGB8888)
+    ///     .with_modifier(DRM_FORMAT_MOD_LINEAR);
+    /// ```
+    pub fn new(fd: c_int, width: u32, height: u32, format: u32) -> Self {
+        Self {
+            fd,
+            format,
+            modifier: 0x00ff_ffff_ffff_ffff,
+            width,
+            height,
+        }
+    }
+
+    /// Set the DRM format modifier of the buffer.
+    ///
+    /// See [`new`][Self::new] for an example.
+    pub fn with_modifier(mut self, modifier: u64) -> Self {
+        self.modifier = modifier;
+        self
     }
 }
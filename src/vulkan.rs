@@ -0,0 +1,114 @@
+use core::ffi::{c_void, CStr};
+use core::ptr::NonNull;
+
+use crate::RawDisplayHandle;
+
+/// Raw display handle for Vulkan's `VK_KHR_display` extension.
+///
+/// This is used when rendering directly to a display without any windowing system, where the
+/// surface is created with `vkCreateDisplayPlaneSurfaceKHR`.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VulkanDisplayHandle {
+    /// The `VkPhysicalDevice` that the display is attached to.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::non_null"))]
+    pub physical_device: NonNull<c_void>,
+    /// The `VkDisplayKHR` handle.
+    ///
+    /// This is a non-dispatchable handle, and as such is always 64 bits wide.
+    pub display: u64,
+}
+
+impl VulkanDisplayHandle {
+    /// Create a new handle to a display.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::VulkanDisplayHandle;
+    /// # type VkPhysicalDevice = ();
+    /// #
+    /// let physical_device: NonNull<VkPhysicalDevice>;
+    /// let display: u64; // VkDisplayKHR
+    /// # physical_device = NonNull::from(&());
+    /// # display = 1;
+    /// let handle = VulkanDisplayHandle::new(physical_device.cast(), display);
+    /// ```
+    pub fn new(physical_device: NonNull<c_void>, display: u64) -> Self {
+        Self {
+            physical_device,
+            display,
+        }
+    }
+}
+
+const fn ext(name: &'static [u8]) -> &'static CStr {
+    // SAFETY: Only called with nul-terminated extension names without interior nul bytes.
+    unsafe { CStr::from_bytes_with_nul_unchecked(name) }
+}
+
+const KHR_SURFACE: &CStr = ext(b"VK_KHR_surface\0");
+const KHR_DISPLAY: &CStr = ext(b"VK_KHR_display\0");
+
+const METAL: &[&CStr] = &[KHR_SURFACE, ext(b"VK_EXT_metal_surface\0")];
+const OHOS: &[&CStr] = &[KHR_SURFACE, ext(b"VK_OHOS_surface\0")];
+const XLIB: &[&CStr] = &[KHR_SURFACE, ext(b"VK_KHR_xlib_surface\0")];
+const XCB: &[&CStr] = &[KHR_SURFACE, ext(b"VK_KHR_xcb_surface\0")];
+const WAYLAND: &[&CStr] = &[KHR_SURFACE, ext(b"VK_KHR_wayland_surface\0")];
+const DRM: &[&CStr] = &[
+    KHR_SURFACE,
+    KHR_DISPLAY,
+    ext(b"VK_EXT_direct_mode_display\0"),
+    ext(b"VK_EXT_acquire_drm_display\0"),
+];
+const WIN32: &[&CStr] = &[KHR_SURFACE, ext(b"VK_KHR_win32_surface\0")];
+const ANDROID: &[&CStr] = &[KHR_SURFACE, ext(b"VK_KHR_android_surface\0")];
+const SCREEN: &[&CStr] = &[KHR_SURFACE, ext(b"VK_QNX_screen_surface\0")];
+const DISPLAY: &[&CStr] = &[KHR_SURFACE, KHR_DISPLAY];
+const VI: &[&CStr] = &[KHR_SURFACE, ext(b"VK_NN_vi_surface\0")];
+const HEADLESS: &[&CStr] = &[KHR_SURFACE, ext(b"VK_EXT_headless_surface\0")];
+
+/// Get the Vulkan instance extensions that are required to create a surface on a display.
+///
+/// This always includes `VK_KHR_surface`, along with the platform-specific surface extension
+/// (such as `VK_KHR_xlib_surface` for [`RawDisplayHandle::Xlib`]). AppKit and UIKit use
+/// `VK_EXT_metal_surface`, which is supported by MoltenVK.
+///
+/// An empty slice is returned for displays that Vulkan cannot create surfaces on, such as
+/// [`RawDisplayHandle::Web`] or [`RawDisplayHandle::Gbm`].
+///
+/// # Example
+///
+/// ```
+/// # use raw_window_handle::{required_surface_extensions, RawDisplayHandle, WindowsDisplayHandle};
+/// let display = RawDisplayHandle::from(WindowsDisplayHandle::new());
+/// let extensions: Vec<_> = required_surface_extensions(&display)
+///     .iter()
+///     .map(|ext| ext.to_str().unwrap())
+///     .collect();
+/// assert_eq!(extensions, ["VK_KHR_surface", "VK_KHR_win32_surface"]);
+/// ```
+pub fn required_surface_extensions(display: &RawDisplayHandle) -> &'static [&'static CStr] {
+    match display {
+        RawDisplayHandle::UiKit(_) | RawDisplayHandle::AppKit(_) => METAL,
+        RawDisplayHandle::Ohos(_) => OHOS,
+        RawDisplayHandle::Xlib(_) => XLIB,
+        RawDisplayHandle::Xcb(_) => XCB,
+        RawDisplayHandle::Wayland(_) => WAYLAND,
+        RawDisplayHandle::Drm(_) => DRM,
+        RawDisplayHandle::Windows(_) => WIN32,
+        RawDisplayHandle::Android(_) => ANDROID,
+        RawDisplayHandle::Screen(_) => SCREEN,
+        RawDisplayHandle::Vulkan(_) => DISPLAY,
+        RawDisplayHandle::Switch(_) => VI,
+        RawDisplayHandle::Headless(_) => HEADLESS,
+        RawDisplayHandle::Orbital(_)
+        | RawDisplayHandle::Gbm(_)
+        | RawDisplayHandle::Web(_)
+        | RawDisplayHandle::Haiku(_)
+        | RawDisplayHandle::WebWorker(_) => &[],
+    }
+}
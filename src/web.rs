@@ -1,11 +1,17 @@
+use core::cmp::Ordering;
 use core::ffi::c_void;
+use core::fmt;
+use core::hash::{Hash, Hasher};
 use core::ptr::NonNull;
 
 use super::DisplayHandle;
 
 /// Raw display handle for the Web.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[repr(C)]
 pub struct WebDisplayHandle {}
 
 impl WebDisplayHandle {
@@ -18,7 +24,7 @@ impl WebDisplayHandle {
     /// # use raw_window_handle::WebDisplayHandle;
     /// let handle = WebDisplayHandle::new();
     /// ```
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Self {}
     }
 }
@@ -43,9 +49,64 @@ impl DisplayHandle<'static> {
     }
 }
 
+/// Raw display handle for a Web Worker.
+///
+/// This is used instead of [`WebDisplayHandle`] when rendering from a [Web Worker], where there is
+/// no `Document` or `Window`. Only an `OffscreenCanvas` can be used in this context, see
+/// [`WebOffscreenCanvasWindowHandle`].
+///
+/// [Web Worker]: https://developer.mozilla.org/en-US/docs/Web/API/Web_Workers_API
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[repr(C)]
+pub struct WebWorkerDisplayHandle {}
+
+impl WebWorkerDisplayHandle {
+    /// Create a new empty display handle.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use raw_window_handle::WebWorkerDisplayHandle;
+    /// let handle = WebWorkerDisplayHandle::new();
+    /// ```
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+impl DisplayHandle<'static> {
+    /// Create a Web Worker-based display handle.
+    ///
+    /// As no data is borrowed by this handle, it is completely safe to create. This function
+    /// may be useful to windowing framework implementations that want to avoid unsafe code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use raw_window_handle::{DisplayHandle, HasDisplayHandle};
+    /// # fn do_something(rwh: impl HasDisplayHandle) { let _ = rwh; }
+    /// let handle = DisplayHandle::web_worker();
+    /// do_something(handle);
+    /// ```
+    pub fn web_worker() -> Self {
+        // SAFETY: No data is borrowed.
+        unsafe { Self::borrow_raw(WebWorkerDisplayHandle::new().into()) }
+    }
+}
+
 /// Raw window handle for the Web.
+///
+/// The comparison and hashing implementations compare [`scale_factor`][Self::scale_factor] by its
+/// bit pattern, so that they are well-behaved even if it is NaN.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[repr(C)]
 pub struct WebWindowHandle {
     /// An ID value inserted into the [data attributes] of the canvas element as '`raw-handle`'.
     ///
@@ -55,6 +116,56 @@ pub struct WebWindowHandle {
     ///
     /// [data attributes]: https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/data-*
     pub id: u32,
+    /// Explicit padding, so that the handle has no uninitialized bytes and can be `Pod`.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    _padding: u32,
+    /// A hint for the ratio of physical pixels to CSS pixels of the canvas, as given by
+    /// [`devicePixelRatio`]. Defaults to `1.0`.
+    ///
+    /// This may be used to size the drawing buffer of the canvas. A value that is not positive
+    /// and finite means that the scale factor is unknown. In particular, a zeroed handle (as
+    /// created with `bytemuck::Zeroable`) has a scale factor of `0.0`.
+    ///
+    /// [`devicePixelRatio`]: https://developer.mozilla.org/en-US/docs/Web/API/Window/devicePixelRatio
+    pub scale_factor: f64,
+}
+
+impl fmt::Debug for WebWindowHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebWindowHandle")
+            .field("id", &self.id)
+            .field("scale_factor", &self.scale_factor)
+            .finish()
+    }
+}
+
+impl PartialEq for WebWindowHandle {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for WebWindowHandle {}
+
+impl PartialOrd for WebWindowHandle {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for WebWindowHandle {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.id
+            .cmp(&other.id)
+            .then_with(|| self.scale_factor.total_cmp(&other.scale_factor))
+    }
+}
+
+impl Hash for WebWindowHandle {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+        self.scale_factor.to_bits().hash(state);
+    }
 }
 
 impl WebWindowHandle {
@@ -70,7 +181,85 @@ impl WebWindowHandle {
     /// let handle = WebWindowHandle::new(id);
     /// ```
     pub fn new(id: u32) -> Self {
-        Self { id }
+        Self {
+            id,
+            _padding: 0,
+            scale_factor: 1.0,
+        }
+    }
+
+    /// Set the scale factor hint of the canvas.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use raw_window_handle::WebWindowHandle;
+    /// #
+    /// let id: u32 = 0; // canvas.rawHandle;
+    /// let scale_factor: f64 = 2.0; // window.devicePixelRatio;
+    /// let handle = WebWindowHandle::new(id).with_scale_factor(scale_factor);
+    /// ```
+    pub fn with_scale_factor(mut self, scale_factor: f64) -> Self {
+        self.scale_factor = scale_factor;
+        self
+    }
+}
+
+/// Raw window handle for a Web canvas identified by a CSS selector.
+///
+/// This is used by the [Emscripten] toolchain, which identifies canvases by a CSS selector string
+/// such as `#canvas`, instead of through the `wasm-bindgen` heap.
+///
+/// [Emscripten]: https://emscripten.org/
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WebCanvasSelectorWindowHandle {
+    /// A pointer to the UTF-8 encoded CSS selector.
+    ///
+    /// The selector is not required to be nul-terminated.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::non_null"))]
+    pub selector: NonNull<u8>,
+    /// The length of the selector in bytes.
+    pub len: usize,
+}
+
+impl WebCanvasSelectorWindowHandle {
+    /// Create a new handle from a pointer to a CSS selector.
+    ///
+    /// The selector must be valid UTF-8, and must stay alive and unmodified for as long as the
+    /// handle is in use.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::WebCanvasSelectorWindowHandle;
+    /// let selector: &'static str = "#canvas";
+    /// let handle = WebCanvasSelectorWindowHandle::new(
+    ///     NonNull::from(selector.as_bytes()).cast(),
+    ///     selector.len(),
+    /// );
+    /// ```
+    pub fn new(selector: NonNull<u8>, len: usize) -> Self {
+        Self { selector, len }
+    }
+
+    /// Get the CSS selector as a string.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must point to `len` bytes of valid UTF-8. This is ensured if this handle was
+    /// borrowed from [`WindowHandle`][crate::WindowHandle].
+    pub unsafe fn as_str(&self) -> &str {
+        // SAFETY: Upheld by the caller.
+        unsafe {
+            core::str::from_utf8_unchecked(core::slice::from_raw_parts(
+                self.selector.as_ptr(),
+                self.len,
+            ))
+        }
     }
 }
 
@@ -78,7 +267,8 @@ impl WebWindowHandle {
 ///
 /// [`wasm-bindgen`]: https://crates.io/crates/wasm-bindgen
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WebCanvasWindowHandle {
     /// A pointer to the [`JsValue`] of an [`HtmlCanvasElement`].
     ///
@@ -95,6 +285,7 @@ pub struct WebCanvasWindowHandle {
     // the binary will simply fail to compile!
     //
     // Reference: TODO
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::non_null"))]
     pub obj: NonNull<c_void>,
 }
 
@@ -120,6 +311,36 @@ impl WebCanvasWindowHandle {
     pub fn new(obj: NonNull<c_void>) -> Self {
         Self { obj }
     }
+
+    /// Create a new handle from a typed pointer to the [`JsValue`] of an [`HtmlCanvasElement`].
+    ///
+    /// This is equivalent to [`new`][Self::new], but avoids having to cast the pointer first. The
+    /// pointer must refer to the `JsValue` of an `HtmlCanvasElement`.
+    ///
+    /// [`JsValue`]: https://docs.rs/wasm-bindgen/latest/wasm_bindgen/struct.JsValue.html
+    /// [`HtmlCanvasElement`]: https://docs.rs/web-sys/latest/web_sys/struct.HtmlCanvasElement.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::WebCanvasWindowHandle;
+    /// # type JsValue = ();
+    /// let value: &JsValue;
+    /// # value = &();
+    /// let handle = WebCanvasWindowHandle::from_non_null(NonNull::from(value));
+    /// assert_eq!(handle.as_non_null(), NonNull::from(value).cast());
+    /// ```
+    pub fn from_non_null<T>(obj: NonNull<T>) -> Self {
+        Self::new(obj.cast())
+    }
+
+    /// Get the pointer to the [`JsValue`].
+    ///
+    /// [`JsValue`]: https://docs.rs/wasm-bindgen/latest/wasm_bindgen/struct.JsValue.html
+    pub fn as_non_null(&self) -> NonNull<c_void> {
+        self.obj
+    }
 }
 
 #[cfg(all(target_family = "wasm", feature = "wasm-bindgen-0-2"))]
@@ -153,7 +374,8 @@ impl WebCanvasWindowHandle {
 ///
 /// [`wasm-bindgen`]: https://crates.io/crates/wasm-bindgen
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WebOffscreenCanvasWindowHandle {
     /// A pointer to the [`JsValue`] of an [`OffscreenCanvas`].
     ///
@@ -164,7 +386,15 @@ pub struct WebOffscreenCanvasWindowHandle {
     /// [`OffscreenCanvas`]: https://docs.rs/web-sys/latest/web_sys/struct.OffscreenCanvas.html
     //
     // SAFETY: See WebCanvasWindowHandle.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::non_null"))]
     pub obj: NonNull<c_void>,
+    /// A pointer to the [`JsValue`] of a [`MessagePort`] used to communicate with the worker that
+    /// the canvas was transferred to, if any.
+    ///
+    /// [`JsValue`]: https://docs.rs/wasm-bindgen/latest/wasm_bindgen/struct.JsValue.html
+    /// [`MessagePort`]: https://docs.rs/web-sys/latest/web_sys/struct.MessagePort.html
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::option_non_null"))]
+    pub message_port: Option<NonNull<c_void>>,
 }
 
 impl WebOffscreenCanvasWindowHandle {
@@ -187,7 +417,63 @@ impl WebOffscreenCanvasWindowHandle {
     /// let mut handle = WebOffscreenCanvasWindowHandle::new(obj);
     /// ```
     pub fn new(obj: NonNull<c_void>) -> Self {
-        Self { obj }
+        Self {
+            obj,
+            message_port: None,
+        }
+    }
+
+    /// Set the [`MessagePort`] of the worker that the canvas was transferred to.
+    ///
+    /// [`MessagePort`]: https://docs.rs/web-sys/latest/web_sys/struct.MessagePort.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::ffi::c_void;
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::WebOffscreenCanvasWindowHandle;
+    /// # type JsValue = ();
+    /// let canvas: &JsValue;
+    /// let port: &JsValue;
+    /// # canvas = &();
+    /// # port = &();
+    /// let handle = WebOffscreenCanvasWindowHandle::from_non_null(NonNull::from(canvas))
+    ///     .with_message_port(Some(NonNull::from(port).cast()));
+    /// ```
+    pub fn with_message_port(mut self, message_port: Option<NonNull<c_void>>) -> Self {
+        self.message_port = message_port;
+        self
+    }
+
+    /// Create a new handle from a typed pointer to the [`JsValue`] of an [`OffscreenCanvas`].
+    ///
+    /// This is equivalent to [`new`][Self::new], but avoids having to cast the pointer first. The
+    /// pointer must refer to the `JsValue` of an `OffscreenCanvas`.
+    ///
+    /// [`JsValue`]: https://docs.rs/wasm-bindgen/latest/wasm_bindgen/struct.JsValue.html
+    /// [`OffscreenCanvas`]: https://docs.rs/web-sys/latest/web_sys/struct.OffscreenCanvas.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::WebOffscreenCanvasWindowHandle;
+    /// # type JsValue = ();
+    /// let value: &JsValue;
+    /// # value = &();
+    /// let handle = WebOffscreenCanvasWindowHandle::from_non_null(NonNull::from(value));
+    /// assert_eq!(handle.as_non_null(), NonNull::from(value).cast());
+    /// ```
+    pub fn from_non_null<T>(obj: NonNull<T>) -> Self {
+        Self::new(obj.cast())
+    }
+
+    /// Get the pointer to the [`JsValue`].
+    ///
+    /// [`JsValue`]: https://docs.rs/wasm-bindgen/latest/wasm_bindgen/struct.JsValue.html
+    pub fn as_non_null(&self) -> NonNull<c_void> {
+        self.obj
     }
 }
 
@@ -0,0 +1,87 @@
+use alloc::collections::BTreeMap;
+
+use crate::{HandleError, HasWindowHandle, RawWindowHandle};
+
+/// A set of windows, deduplicated by their [identity][RawWindowHandle::identity].
+///
+/// Two handles to the same window are considered equal, even if one of them has more optional
+/// fields filled in. This is useful for e.g. caches of GPU surfaces that should only contain one
+/// surface per window.
+///
+/// Only a snapshot of each handle is stored, so the set does not keep the windows alive, see
+/// [`HasWindowHandle::raw_window_handle_snapshot`].
+///
+/// # Example
+///
+/// ```
+/// # use raw_window_handle::{RawWindowHandle, WebWindowHandle, WindowHandle, WindowHandleSet};
+/// # let raw = RawWindowHandle::from(WebWindowHandle::new(1));
+/// # let window = unsafe { WindowHandle::borrow_raw(raw) };
+/// let mut set = WindowHandleSet::new();
+/// assert_eq!(set.insert(&window).unwrap(), true);
+/// assert_eq!(set.insert(&window).unwrap(), false);
+/// assert!(set.contains(&window).unwrap());
+///
+/// assert_eq!(set.remove(&window).unwrap(), true);
+/// assert!(set.is_empty());
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct WindowHandleSet {
+    windows: BTreeMap<u64, RawWindowHandle>,
+}
+
+impl WindowHandleSet {
+    /// Create a new, empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a window to the set.
+    ///
+    /// Returns `true` if the window was not already in the set. If it was, the stored handle is
+    /// left unchanged.
+    pub fn insert<H: HasWindowHandle + ?Sized>(&mut self, window: &H) -> Result<bool, HandleError> {
+        let raw = window.raw_window_handle_snapshot()?;
+        let mut inserted = false;
+        self.windows.entry(raw.identity()).or_insert_with(|| {
+            inserted = true;
+            raw
+        });
+        Ok(inserted)
+    }
+
+    /// Check whether a window is in the set.
+    pub fn contains<H: HasWindowHandle + ?Sized>(&self, window: &H) -> Result<bool, HandleError> {
+        let raw = window.raw_window_handle_snapshot()?;
+        Ok(self.windows.contains_key(&raw.identity()))
+    }
+
+    /// Remove a window from the set.
+    ///
+    /// Returns `true` if the window was in the set.
+    pub fn remove<H: HasWindowHandle + ?Sized>(&mut self, window: &H) -> Result<bool, HandleError> {
+        let raw = window.raw_window_handle_snapshot()?;
+        Ok(self.windows.remove(&raw.identity()).is_some())
+    }
+
+    /// The number of windows in the set.
+    pub fn len(&self) -> usize {
+        self.windows.len()
+    }
+
+    /// Whether the set contains no windows.
+    pub fn is_empty(&self) -> bool {
+        self.windows.is_empty()
+    }
+
+    /// Iterate over the handles in the set, ordered by their identity.
+    pub fn iter(&self) -> impl Iterator<Item = RawWindowHandle> + '_ {
+        self.windows.values().copied()
+    }
+
+    /// Remove all windows from the set.
+    pub fn clear(&mut self) {
+        self.windows.clear();
+    }
+}
@@ -1,4 +1,5 @@
 use core::ffi::c_void;
+use core::fmt;
 use core::num::NonZeroIsize;
 use core::ptr::NonNull;
 
@@ -8,7 +9,10 @@ use super::DisplayHandle;
 ///
 /// It can be used regardless of Windows window backend.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Zeroable, bytemuck::Pod))]
+#[repr(C)]
 pub struct WindowsDisplayHandle {}
 
 impl WindowsDisplayHandle {
@@ -20,8 +24,10 @@ impl WindowsDisplayHandle {
     /// ```
     /// # use raw_window_handle::WindowsDisplayHandle;
     /// let handle = WindowsDisplayHandle::new();
+    /// // Can also be used in constant expressions.
+    /// const HANDLE: WindowsDisplayHandle = WindowsDisplayHandle::new();
     /// ```
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Self {}
     }
 }
@@ -48,12 +54,42 @@ impl DisplayHandle<'static> {
 
 /// Raw window handle for Win32.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Win32WindowHandle {
     /// A Win32 `HWND` handle.
     pub hwnd: NonZeroIsize,
     /// The `GWLP_HINSTANCE` associated with this type's `HWND`.
     pub hinstance: Option<NonZeroIsize>,
+    /// A device context (`HDC`) for this type's `HWND`.
+    ///
+    /// If provided, the device context must remain valid for the lifetime of the handle.
+    pub hdc: Option<NonZeroIsize>,
+    /// The parent `HWND` of this type's `HWND`, or `None` if it is a top-level window.
+    ///
+    /// This may be provided for child windows embedded into another window, possibly one owned
+    /// by another process, so that consumers can clip and position their rendering accordingly.
+    pub parent_hwnd: Option<NonZeroIsize>,
+}
+
+/// Formats a Win32 handle in hexadecimal, like the pointers in the other handle types.
+struct Hex(NonZeroIsize);
+
+impl fmt::Debug for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x}", self.0)
+    }
+}
+
+impl fmt::Debug for Win32WindowHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Win32WindowHandle")
+            .field("hwnd", &Hex(self.hwnd))
+            .field("hinstance", &self.hinstance.map(Hex))
+            .field("hdc", &self.hdc.map(Hex))
+            .field("parent_hwnd", &self.parent_hwnd.map(Hex))
+            .finish()
+    }
 }
 
 impl Win32WindowHandle {
@@ -73,26 +109,162 @@ impl Win32WindowHandle {
     /// #
     /// let window: HWND;
     /// # window = HWND(1);
-    /// let mut handle = Win32WindowHandle::new(NonZeroIsize::new(window.0).unwrap());
-    /// // Optionally set the GWLP_HINSTANCE.
+    /// // Optionally get the GWLP_HINSTANCE.
     /// # #[cfg(only_for_showcase)]
-    /// let hinstance = NonZeroIsize::new(unsafe { GetWindowLongPtrW(window, GWLP_HINSTANCE) }).unwrap();
+    /// let hinstance = NonZeroIsize::new(unsafe { GetWindowLongPtrW(window, GWLP_HINSTANCE) });
     /// # let hinstance = None;
-    /// handle.hinstance = hinstance;
+    /// let handle = Win32WindowHandle::new(NonZeroIsize::new(window.0).unwrap())
+    ///     .with_hinstance(hinstance);
     /// ```
     pub fn new(hwnd: NonZeroIsize) -> Self {
         Self {
             hwnd,
             hinstance: None,
+            hdc: None,
+            parent_hwnd: None,
+        }
+    }
+
+    /// Set the `GWLP_HINSTANCE` of the window.
+    ///
+    /// See [`new`][Self::new] for an example.
+    pub fn with_hinstance(mut self, hinstance: Option<NonZeroIsize>) -> Self {
+        self.hinstance = hinstance;
+        self
+    }
+
+    /// Set the device context (`HDC`) of the window.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::num::NonZeroIsize;
+    /// # use raw_window_handle::Win32WindowHandle;
+    /// # struct HWND(isize);
+    /// # struct HDC(isize);
+    /// #
+    /// let window: HWND;
+    /// # window = HWND(1);
+    /// # #[cfg(only_for_showcase)]
+    /// let hdc = unsafe { GetDC(window) };
+    /// # let hdc = HDC(2);
+    /// let handle = Win32WindowHandle::new(NonZeroIsize::new(window.0).unwrap())
+    ///     .with_hdc(NonZeroIsize::new(hdc.0));
+    /// ```
+    pub fn with_hdc(mut self, hdc: Option<NonZeroIsize>) -> Self {
+        self.hdc = hdc;
+        self
+    }
+
+    /// Set the parent `HWND` of the window.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::num::NonZeroIsize;
+    /// # use raw_window_handle::Win32WindowHandle;
+    /// # struct HWND(isize);
+    /// #
+    /// let window: HWND;
+    /// # window = HWND(1);
+    /// # #[cfg(only_for_showcase)]
+    /// let parent = unsafe { GetAncestor(window, GA_PARENT) };
+    /// # let parent = HWND(2);
+    /// let handle = Win32WindowHandle::new(NonZeroIsize::new(window.0).unwrap())
+    ///     .with_parent(NonZeroIsize::new(parent.0));
+    /// ```
+    pub fn with_parent(mut self, parent_hwnd: Option<NonZeroIsize>) -> Self {
+        self.parent_hwnd = parent_hwnd;
+        self
+    }
+
+    /// Get the `HWND` as a pointer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::num::NonZeroIsize;
+    /// # use raw_window_handle::Win32WindowHandle;
+    /// let handle = Win32WindowHandle::new(NonZeroIsize::new(0x1000).unwrap());
+    /// assert_eq!(handle.hwnd_ptr().as_ptr() as isize, 0x1000);
+    /// assert_eq!(handle.hinstance_ptr(), None);
+    /// ```
+    pub fn hwnd_ptr(&self) -> NonNull<c_void> {
+        // SAFETY: `hwnd` is non-zero.
+        unsafe { NonNull::new_unchecked(self.hwnd.get() as *mut c_void) }
+    }
+
+    /// Get the `HINSTANCE` as a pointer, if it was provided.
+    pub fn hinstance_ptr(&self) -> Option<NonNull<c_void>> {
+        self.hinstance
+            .and_then(|hinstance| NonNull::new(hinstance.get() as *mut c_void))
+    }
+}
+
+#[cfg(all(windows, feature = "windows-0-58"))]
+#[cfg_attr(docsrs, doc(cfg(all(windows, feature = "windows-0-58"))))]
+/// These implementations are only available when `windows-0-58` is enabled.
+impl Win32WindowHandle {
+    /// Create a new `Win32WindowHandle` from a [`windows::Win32::Foundation::HWND`].
+    ///
+    /// Returns `None` if the `HWND` is null.
+    pub fn from_hwnd(hwnd: windows::Win32::Foundation::HWND) -> Option<Self> {
+        NonZeroIsize::new(hwnd.0 as isize).map(Self::new)
+    }
+
+    /// Set the `GWLP_HINSTANCE` of the window from a [`windows::Win32::Foundation::HINSTANCE`].
+    ///
+    /// A null `HINSTANCE` is stored as `None`.
+    pub fn with_hinstance_handle(self, hinstance: windows::Win32::Foundation::HINSTANCE) -> Self {
+        self.with_hinstance(NonZeroIsize::new(hinstance.0 as isize))
+    }
+
+    /// Get the `HWND` as a [`windows::Win32::Foundation::HWND`].
+    pub fn as_hwnd(&self) -> windows::Win32::Foundation::HWND {
+        windows::Win32::Foundation::HWND(self.hwnd_ptr().as_ptr())
+    }
+
+    /// Get the `HINSTANCE` as a [`windows::Win32::Foundation::HINSTANCE`].
+    ///
+    /// Returns a null `HINSTANCE` if none was provided.
+    pub fn as_hinstance(&self) -> windows::Win32::Foundation::HINSTANCE {
+        windows::Win32::Foundation::HINSTANCE(
+            self.hinstance_ptr()
+                .map_or(core::ptr::null_mut(), NonNull::as_ptr),
+        )
+    }
+
+    /// Fill in [`hinstance`][Self::hinstance] by querying the window's `GWLP_HINSTANCE`.
+    ///
+    /// Does nothing if `hinstance` is already set. Returns
+    /// [`HandleError::Unavailable`][crate::HandleError::Unavailable] if the `HWND` does not refer
+    /// to a window, or if the window has no `HINSTANCE`.
+    pub fn fill_hinstance(&mut self) -> Result<(), crate::HandleError> {
+        #[cfg(target_pointer_width = "64")]
+        use windows::Win32::UI::WindowsAndMessaging::GetWindowLongPtrW;
+        #[cfg(target_pointer_width = "32")]
+        use windows::Win32::UI::WindowsAndMessaging::GetWindowLongW as GetWindowLongPtrW;
+        use windows::Win32::UI::WindowsAndMessaging::GWLP_HINSTANCE;
+
+        if self.hinstance.is_some() {
+            return Ok(());
         }
+        // `GetWindowLongPtrW` is only a macro for `GetWindowLongW` on 32-bit Windows.
+        // SAFETY: `GetWindowLongPtrW` validates the `HWND`, and returns zero if it is invalid.
+        let hinstance = unsafe { GetWindowLongPtrW(self.as_hwnd(), GWLP_HINSTANCE) };
+        self.hinstance =
+            Some(NonZeroIsize::new(hinstance as isize).ok_or(crate::HandleError::Unavailable)?);
+        Ok(())
     }
 }
 
 /// Raw window handle for WinRT.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WinRtWindowHandle {
     /// A WinRT `CoreWindow` handle.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::non_null"))]
     pub core_window: NonNull<c_void>,
 }
 
@@ -115,3 +287,36 @@ impl WinRtWindowHandle {
         Self { core_window }
     }
 }
+
+/// Raw window handle for a WinUI/UWP `SwapChainPanel`.
+///
+/// Swap chains for such panels are created with `CreateSwapChainForComposition`, and then
+/// associated with the panel through `ISwapChainPanelNative`.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WinUiSwapChainPanelWindowHandle {
+    /// A pointer to an `ISwapChainPanelNative`.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_ptr::non_null"))]
+    pub panel: NonNull<c_void>,
+}
+
+impl WinUiSwapChainPanelWindowHandle {
+    /// Create a new handle to a swap chain panel.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use core::ptr::NonNull;
+    /// # use raw_window_handle::WinUiSwapChainPanelWindowHandle;
+    /// # type ISwapChainPanelNative = ();
+    /// #
+    /// let panel: NonNull<ISwapChainPanelNative>;
+    /// # panel = NonNull::from(&());
+    /// let handle = WinUiSwapChainPanelWindowHandle::new(panel.cast());
+    /// ```
+    pub fn new(panel: NonNull<c_void>) -> Self {
+        Self { panel }
+    }
+}